@@ -0,0 +1,46 @@
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day};
+use eyre::{eyre, Result};
+
+pub struct Day1 {}
+
+register_day!(2020, 1, Day1 {});
+
+impl Day for Day1 {
+    type Input<'a> = Vec<i32>;
+
+    fn parse<'a>(&self, s: &'a str) -> Result<Self::Input<'a>> {
+        Ok(s.split('\n')
+            .filter(|sub| !sub.is_empty())
+            .map(|sub| number(s, sub))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part_1(&self, entries: &Self::Input<'_>) -> Result<Answer> {
+        for sub in entries {
+            for sub2 in entries {
+                //println!("{}", sub);
+                //println!("{}", sub2);
+                if sub + sub2 == 2020 {
+                    return Ok((sub * sub2).into());
+                }
+            }
+        }
+        Err(eyre!("no pair sums to 2020"))
+    }
+
+    fn part_2(&self, entries: &Self::Input<'_>) -> Result<Answer> {
+        for sub in entries {
+            for sub2 in entries {
+                for sub3 in entries {
+                    //println!("{}", sub);
+                    //println!("{}", sub2);
+                    if sub + sub2 + sub3 == 2020 {
+                        return Ok((sub * sub2 * sub3).into());
+                    }
+                }
+            }
+        }
+        Err(eyre!("no triple sums to 2020"))
+    }
+}
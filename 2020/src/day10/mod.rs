@@ -1,9 +1,10 @@
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day, ParseError};
 use eyre::Result;
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::fs::read_to_string;
 
-fn part1(input: Vec<isize>) {
+pub fn part1(input: &[isize]) -> isize {
     let mut last = input[0];
     // first adapter always adds one
     let mut one_jolt_diffs = 1;
@@ -19,12 +20,13 @@ fn part1(input: Vec<isize>) {
     }
     // add the final adapter, always three higher than the highest.
     three_jolt_diffs += 1;
-    println!(
-        "{} one jolt diffs * {} three jolt diffs = {}",
-        one_jolt_diffs,
-        three_jolt_diffs,
-        one_jolt_diffs * three_jolt_diffs
-    );
+    //println!(
+    //"{} one jolt diffs * {} three jolt diffs = {}",
+    //one_jolt_diffs,
+    //three_jolt_diffs,
+    //one_jolt_diffs * three_jolt_diffs
+    //);
+    one_jolt_diffs * three_jolt_diffs
 }
 
 fn find_possible_solutions(
@@ -33,7 +35,7 @@ fn find_possible_solutions(
     visited: &mut HashMap<isize, isize>,
 ) -> isize {
     // memoized
-    return if let Some(&v) = visited.get(&value) {
+    if let Some(&v) = visited.get(&value) {
         v
     } else if value == 0 {
         // reached the end
@@ -49,28 +51,38 @@ fn find_possible_solutions(
         // memoize
         visited.insert(value, result);
         result
-    };
+    }
 }
 
-fn part2(input: &HashSet<isize>) -> isize {
+pub fn part2(input: &HashSet<isize>) -> isize {
     find_possible_solutions(input, *input.iter().max().unwrap(), &mut HashMap::new())
 }
 
-fn main() -> Result<()> {
-    let mut input: Vec<isize> = read_to_string("src/day10/input.txt")?
-        .split('\n')
-        .filter(|x| !x.is_empty())
-        .map(|x| x.parse::<isize>().unwrap())
-        .collect();
-    input.sort_unstable();
-    part1(input);
-    let input = read_to_string("src/day10/input.txt")?
-        .split('\n')
-        .filter(|x| !x.is_empty())
-        .map(|x| x.parse::<isize>().unwrap())
-        .collect();
-    let result = part2(&input);
-    //println!("final result: {:#?}", result);
-    println!("final result: {:?}", result);
-    Ok(())
+pub struct Day10 {}
+
+register_day!(2020, 10, Day10 {});
+
+impl Day for Day10 {
+    type Input<'a> = Vec<isize>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        let mut adapters: Vec<isize> = input
+            .split('\n')
+            .filter(|x| !x.is_empty())
+            .map(|x| number(input, x))
+            .collect::<Result<_, _>>()?;
+        if adapters.is_empty() {
+            return Err(ParseError::end(input, "at least one adapter").into());
+        }
+        adapters.sort_unstable();
+        Ok(adapters)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part2(&input.iter().copied().collect()).into())
+    }
 }
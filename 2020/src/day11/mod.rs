@@ -0,0 +1,138 @@
+use aoc_core::automaton::{Automaton, LifeRule, Neighborhood, Topology};
+use aoc_core::day::no_such_option;
+use aoc_core::grid::{FromChar, Grid, Point, NEIGHBORS8};
+use aoc_core::{register_day, Answer, Day};
+use eyre::Result;
+use std::collections::HashMap;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Seat {
+    Floor,
+    Empty,
+    Occupied,
+}
+
+impl FromChar for Seat {
+    const EXPECTED: &'static str = "'L', '#' or '.'";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Seat::Floor),
+            'L' => Some(Seat::Empty),
+            '#' => Some(Seat::Occupied),
+            _ => None,
+        }
+    }
+}
+
+/// More rounds than any seating area this size takes to settle.
+const SETTLE_LIMIT: usize = 10_000;
+
+/// Empty seats fill up when nothing around them is occupied, and people
+/// leave once 4 seats around them are taken.
+pub const ADJACENT_RULE: &str = "B0/S<4";
+
+/// Looking past the floor to the first seat in each direction, people put
+/// up with 5 taken seats before leaving.
+pub const VISIBLE_RULE: &str = "B0/S<5L";
+
+/// The seats of the waiting area, and which seats count as around each.
+struct Seats {
+    seats: Vec<Point>,
+    around: HashMap<Point, Vec<Point>>,
+}
+
+impl Seats {
+    fn new(grid: &Grid<Seat>, neighborhood: Neighborhood) -> Self {
+        let is_seat = |point: &Point| grid[*point] != Seat::Floor;
+        let seats: Vec<Point> = grid.points().filter(is_seat).collect();
+        let around = seats
+            .iter()
+            .map(|&seat| {
+                let around = match neighborhood {
+                    Neighborhood::Moore => grid.neighbors8(seat).filter(is_seat).collect(),
+                    Neighborhood::VonNeumann => grid.neighbors4(seat).filter(is_seat).collect(),
+                    Neighborhood::Sight => NEIGHBORS8
+                        .iter()
+                        .filter_map(|&direction| grid.ray(seat, direction).find(is_seat))
+                        .collect(),
+                    Neighborhood::Hex => unreachable!("settle checks the neighborhood"),
+                };
+                (seat, around)
+            })
+            .collect();
+        Seats { seats, around }
+    }
+}
+
+impl Topology for Seats {
+    type Cell = Point;
+
+    fn neighbors(&self, seat: Point) -> Vec<Point> {
+        self.around[&seat].clone()
+    }
+
+    fn cells(&self) -> Option<Vec<Point>> {
+        Some(self.seats.clone())
+    }
+}
+
+/// Let people shuffle around by `rule` until nobody moves, returning how
+/// many seats end up occupied.
+pub fn settle(grid: &Grid<Seat>, rule: &LifeRule) -> Result<usize> {
+    rule.check_neighborhood(&[
+        Neighborhood::Moore,
+        Neighborhood::VonNeumann,
+        Neighborhood::Sight,
+    ])?;
+    let occupied = grid
+        .iter()
+        .filter(|(_, &seat)| seat == Seat::Occupied)
+        .map(|(point, _)| point);
+    let seats = Seats::new(grid, rule.neighborhood);
+    let mut area = Automaton::new(seats, rule.clone(), occupied);
+    area.run_until_stable(SETTLE_LIMIT)?;
+    Ok(area.count_alive())
+}
+
+pub struct Day11 {
+    rules: [LifeRule; 2],
+}
+
+impl Default for Day11 {
+    fn default() -> Self {
+        let rule = |rule: &str| rule.parse().expect("the puzzle's rules parse");
+        Day11 {
+            rules: [rule(ADJACENT_RULE), rule(VISIBLE_RULE)],
+        }
+    }
+}
+
+register_day!(2020, 11, Day11::default());
+
+impl Day for Day11 {
+    type Input<'a> = Grid<Seat>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(settle(input, &self.rules[0])?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(settle(input, &self.rules[1])?.into())
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "rule" => {
+                let rule: LifeRule = value.parse()?;
+                self.rules = [rule.clone(), rule];
+            }
+            _ => return Err(no_such_option(key)),
+        }
+        Ok(())
+    }
+}
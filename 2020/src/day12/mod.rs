@@ -1,5 +1,6 @@
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day, ParseError};
 use eyre::Result;
-use std::fs::read_to_string;
 use std::ops::Add;
 use std::ops::AddAssign;
 
@@ -62,26 +63,24 @@ impl AddAssign for CardinalDelta {
 impl CardinalDelta {
     fn rotate(&mut self, direction: char, count: u32) {
         for _ in 0..count {
-            let tmp = self.0;
-            self.0 = self.1;
-            self.1 = tmp;
+            std::mem::swap(&mut self.0, &mut self.1);
             match direction {
                 'R' => self.0 = -self.0,
                 'L' => self.1 = -self.1,
-                _ => println!("cannot compute"),
+                _ => eprintln!("cannot compute"),
             }
         }
     }
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
-enum ShipType {
+pub enum ShipType {
     Navigational,
     Waypoint,
 }
 
 #[derive(Copy, Clone, Debug, PartialEq)]
-struct Ship {
+pub struct Ship {
     facing: CardinalDirection,
     delta: CardinalDelta,
     waypoint_delta: CardinalDelta,
@@ -89,7 +88,7 @@ struct Ship {
 }
 
 impl Ship {
-    fn new(ship_type: ShipType) -> Ship {
+    pub fn new(ship_type: ShipType) -> Ship {
         Ship {
             facing: CardinalDirection::East,
             delta: CardinalDelta(0, 0),
@@ -103,7 +102,7 @@ impl Ship {
             ShipType::Navigational => match dir {
                 'L' => self.facing = self.facing + -(ticks as i32),
                 'R' => self.facing = self.facing + ticks as i32,
-                _ => println!("failure!"),
+                _ => eprintln!("failure!"),
             },
             ShipType::Waypoint => self.waypoint_delta.rotate(dir, ticks),
         }
@@ -129,13 +128,13 @@ impl Ship {
         }
     }
 
-    fn move_by(&mut self, direction: char, count: u32) {
+    pub fn move_by(&mut self, direction: char, count: u32) {
         match self.ship_type {
             ShipType::Navigational => match direction {
                 'N' | 'E' | 'S' | 'W' => self.move_in_dir(direction, count as i32),
                 'L' | 'R' => self.rotate(direction, count / 90),
                 'F' => self.move_in_dir(self.facing.char(), count as i32),
-                _ => println!("failure! bad command"),
+                _ => eprintln!("failure! bad command"),
             },
             ShipType::Waypoint => match direction {
                 'N' | 'E' | 'S' | 'W' => self.move_waypoint_in_dir(direction, count as i32),
@@ -145,44 +144,53 @@ impl Ship {
                     self.move_in_dir('N', self.waypoint_delta.0 * count as i32);
                     self.move_in_dir('E', self.waypoint_delta.1 * count as i32);
                 }
-                _ => println!("failure! bad command"),
+                _ => eprintln!("failure! bad command"),
             },
         }
     }
 
-    fn manhattan_delta(&self) -> i32 {
+    pub fn manhattan_delta(&self) -> i32 {
         self.delta.0.abs() + self.delta.1.abs()
     }
 }
 
-fn main() -> Result<()> {
-    let input = read_to_string("src/day12/input.txt")?;
-    // part 1
-    let mut ship = Ship::new(ShipType::Navigational);
-    for command in input.split('\n').filter(|c| !c.is_empty()) {
-        let chars: Vec<char> = command.chars().collect();
-        let dir = &chars[0];
-        let count: u32 = chars[1..].iter().collect::<String>().parse()?;
-        //println!("{} for {}", *dir, count);
-        //println!("before: {:?}", ship);
-        ship.move_by(*dir, count);
-        //println!("after: {:?}", ship);
+pub struct Day12 {}
+
+register_day!(2020, 12, Day12 {});
+
+impl Day for Day12 {
+    type Input<'a> = Vec<(char, u32)>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        let mut commands = vec![];
+        for command in input.split('\n').filter(|c| !c.is_empty()) {
+            let dir = command.chars().next().unwrap();
+            if !"NSEWLRF".contains(dir) {
+                let action = &command[..dir.len_utf8()];
+                return Err(ParseError::at(input, action, "one of N, S, E, W, L, R or F").into());
+            }
+            let count: u32 = number(input, &command[dir.len_utf8()..])?;
+            commands.push((dir, count));
+        }
+        Ok(commands)
     }
-    println!("final ship: {:?}", ship);
-    println!("final manhattan delta: {}", ship.manhattan_delta());
-
-    // part 2
-    let mut ship = Ship::new(ShipType::Waypoint);
-    for command in input.split('\n').filter(|c| !c.is_empty()) {
-        let chars: Vec<char> = command.chars().collect();
-        let dir = &chars[0];
-        let count: u32 = chars[1..].iter().collect::<String>().parse()?;
-        //println!("{} for {}", *dir, count);
-        //println!("before: {:?}", ship);
-        ship.move_by(*dir, count);
-        //println!("after: {:?}", ship);
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        let mut ship = Ship::new(ShipType::Navigational);
+        for &(dir, count) in input {
+            //println!("{} for {}", dir, count);
+            //println!("before: {:?}", ship);
+            ship.move_by(dir, count);
+            //println!("after: {:?}", ship);
+        }
+        Ok(ship.manhattan_delta().into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        let mut ship = Ship::new(ShipType::Waypoint);
+        for &(dir, count) in input {
+            ship.move_by(dir, count);
+        }
+        Ok(ship.manhattan_delta().into())
     }
-    println!("final ship: {:?}", ship);
-    println!("final manhattan delta: {}", ship.manhattan_delta());
-    Ok(())
 }
@@ -0,0 +1,134 @@
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day, ParseError, Part};
+use eyre::{eyre, Result};
+
+/// When we can first leave, and the ID of each bus in the schedule, `None`
+/// where it's out of service.
+pub struct Notes {
+    pub earliest_departure: usize,
+    pub buses: Vec<Option<usize>>,
+}
+
+pub fn parse_notes(input: &str) -> Result<Notes, ParseError> {
+    let mut lines = input.split_whitespace();
+    let earliest_departure = lines
+        .next()
+        .ok_or_else(|| ParseError::end(input, "the earliest departure"))?;
+    let earliest_departure = number(input, earliest_departure)?;
+    let schedule = lines
+        .next()
+        .ok_or_else(|| ParseError::end(input, "a bus schedule"))?;
+    let buses = schedule
+        .split(',')
+        .map(|bus| match bus {
+            "x" => Ok(None),
+            _ => match number(input, bus)? {
+                0 => Err(ParseError::at(input, bus, "a bus ID above zero")),
+                bus_id => Ok(Some(bus_id)),
+            },
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(Notes {
+        earliest_departure,
+        buses,
+    })
+}
+
+pub fn find_earliest_bus_from(notes: &Notes) -> Result<usize> {
+    let earliest_departure = notes.earliest_departure;
+    let schedule: Vec<(usize, usize)> = notes
+        .buses
+        .iter()
+        .flatten()
+        .map(|&bus_id| {
+            //println!("searching for earliest departure of {}", bus_id);
+            let mut result = vec![0; bus_id + 1];
+            result[0] = 1;
+            result[bus_id] = 1;
+            //println!(
+            //    "{} created {:?}",
+            //    bus_id,
+            //    result
+            //        .iter()
+            //        .clone()
+            //        .skip(1)
+            //        .cycle()
+            //        .enumerate()
+            //        .map(|(index, val)| (index + 1, val))
+            //        .skip(earliest_departure - 4)
+            //        .take(25)
+            //        .collect::<Vec<(usize, &usize)>>()
+            //);
+            result
+                .iter()
+                // skip initial departure at t==0
+                .skip(1)
+                // cycle the schedule to allow for arbitrary departure time checks
+                .cycle()
+                .enumerate()
+                // indices are off by one because oof the above skip
+                .map(|(bus_id, val)| (bus_id + 1, val))
+                // skip to the time in question
+                .skip(earliest_departure)
+                // find the next departure
+                .find(|(_, x)| **x == 1)
+                // salient information is contained in the departure time and the bus id
+                .map(|(j, _)| (j, bus_id))
+                .unwrap()
+        })
+        .collect();
+
+    //println!("times: {:?}, buses: {:?}", times, buses);
+    let best_time = schedule
+        .iter()
+        .min_by_key(|(time, _)| time)
+        .ok_or_else(|| eyre!("no buses in service"))?;
+    //println!("best time is {:?}", best_time);
+    Ok((best_time.0 - earliest_departure) * best_time.1)
+}
+
+pub fn find_earliest_consecutive_departures(notes: &Notes) -> usize {
+    let buses: Vec<(usize, usize)> = notes
+        .buses
+        .iter()
+        .enumerate()
+        .filter_map(|(i, bus)| bus.map(|bus_id| (i, bus_id)))
+        .collect();
+    let mut result = 0;
+    let mut lcd = 1;
+    for bus in buses {
+        //println!("bus: {:?}", bus);
+        while (result + bus.0) % bus.1 > 0 {
+            result += lcd;
+        }
+        lcd *= bus.1;
+    }
+    result
+}
+
+pub struct Day13 {}
+
+register_day!(2020, 13, Day13 {});
+
+impl Day for Day13 {
+    type Input<'a> = Notes;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(parse_notes(input)?)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(find_earliest_bus_from(input)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(find_earliest_consecutive_departures(input).into())
+    }
+
+    fn samples(&self, part: Part) -> &'static [u8] {
+        match part {
+            Part::One => &[1],
+            Part::Two => &[1, 2, 3, 4, 5, 6],
+        }
+    }
+}
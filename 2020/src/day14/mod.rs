@@ -0,0 +1,175 @@
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day, ParseError, Part};
+use eyre::Result;
+use std::collections::HashMap;
+
+const INTEGER_SIZE: usize = 36;
+
+#[derive(Debug)]
+pub enum ProgramType {
+    Dma,
+    Decoder,
+}
+
+#[derive(Debug)]
+struct Program {
+    mask: String,
+    memspace: HashMap<usize, usize>,
+    program_type: ProgramType,
+}
+
+impl Program {
+    fn new(program_type: ProgramType) -> Self {
+        Program {
+            mask: "XXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXXX".to_string(),
+            memspace: HashMap::new(),
+            program_type,
+        }
+    }
+
+    fn mask(&mut self, mask_str: &str) {
+        //println!("mask in: {}", mask_str);
+        self.mask = mask_str.to_string();
+    }
+
+    fn set_mem(&mut self, address: usize, value: usize) -> Result<()> {
+        match self.program_type {
+            ProgramType::Dma => {
+                let bin_val = &format!("{:b}", value);
+                let prefix = std::iter::repeat_n('0', INTEGER_SIZE - bin_val.len());
+                let val_iter = prefix.chain(bin_val.chars());
+                let mut masked_vec = vec![];
+                for (mask_ch, val_ch) in self.mask.chars().zip(val_iter) {
+                    match mask_ch {
+                        '0' | '1' => masked_vec.push(mask_ch),
+                        'X' => masked_vec.push(val_ch),
+                        _ => eprintln!("ffailure"),
+                    }
+                }
+                let result = usize::from_str_radix(&masked_vec.iter().collect::<String>(), 2)?;
+                self.memspace.insert(address, result);
+                //println!("final result: {}", result);
+            }
+            ProgramType::Decoder => {
+                let mut addr_str = format!("{:b}", address);
+                //println!("old addr string: {}", addr_str);
+                let num_floating = self.mask.chars().filter(|c| *c == 'X').count();
+                let prefix = std::iter::repeat_n('0', INTEGER_SIZE - addr_str.len());
+                addr_str = prefix.chain(addr_str.chars()).collect();
+                // replace address values with 'X'
+                for (index, ch) in self.mask.chars().enumerate() {
+                    if ch == 'X' {
+                        addr_str = addr_str
+                            .chars()
+                            .enumerate()
+                            .map(|(i, ch)| if i == index { 'X' } else { ch })
+                            .collect();
+                    } else if ch == '1' {
+                        addr_str = addr_str
+                            .chars()
+                            .enumerate()
+                            .map(|(i, ch)| if i == index { '1' } else { ch })
+                            .collect();
+                    }
+                }
+                //println!("new addr string: {}", addr_str);
+
+                let mut addrs_to_write = vec![addr_str];
+                for _ in 0..num_floating {
+                    let mut new_addrs_to_write = vec![];
+                    for addr in &mut addrs_to_write {
+                        new_addrs_to_write.push(addr.clone().replacen('X', "0", 1));
+                        new_addrs_to_write.push(addr.clone().replacen('X', "1", 1));
+                    }
+                    addrs_to_write = new_addrs_to_write;
+                }
+                //println!("created new addresses {:?}", addrs_to_write);
+                for addr in addrs_to_write {
+                    self.memspace
+                        .insert(usize::from_str_radix(&addr, 2)?, value);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn sum(&self) -> usize {
+        self.memspace.values().sum()
+    }
+}
+
+/// A single line of the initialization program.
+pub enum Instruction<'a> {
+    Mask(&'a str),
+    Mem(usize, usize),
+}
+
+pub fn run(instructions: &[Instruction], program_type: ProgramType) -> Result<usize> {
+    let mut program = Program::new(program_type);
+    for instruction in instructions {
+        match *instruction {
+            Instruction::Mask(mask) => program.mask(mask),
+            Instruction::Mem(address, val) => program.set_mem(address, val)?,
+        }
+    }
+    //println!("{:?}", program);
+    Ok(program.sum())
+}
+
+pub struct Day14 {}
+
+register_day!(2020, 14, Day14 {});
+
+impl Day for Day14 {
+    type Input<'a> = Vec<Instruction<'a>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        let mut instructions = vec![];
+        for line in input.split('\n') {
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(mask) = line.strip_prefix("mask = ") {
+                //println!("got mask {}", mask);
+                if mask.len() != INTEGER_SIZE || !mask.chars().all(|c| "01X".contains(c)) {
+                    let expected = format!("{} of 0, 1 or X", INTEGER_SIZE);
+                    return Err(ParseError::at(input, mask, expected).into());
+                }
+                instructions.push(Instruction::Mask(mask));
+            } else if let Some(assignment) = line.strip_prefix("mem[") {
+                let (address_str, val_str) = assignment
+                    .split_once("] = ")
+                    .ok_or_else(|| ParseError::at(input, assignment, "an address and a value"))?;
+                //println!("address: {}, val: {}", address_str, val_str);
+                let address: usize = number(input, address_str)?;
+                let val: usize = number(input, val_str)?;
+                for (text, n) in [(address_str, address), (val_str, val)] {
+                    if n >> INTEGER_SIZE != 0 {
+                        let expected = format!("a {}-bit number", INTEGER_SIZE);
+                        return Err(ParseError::at(input, text, expected).into());
+                    }
+                }
+                instructions.push(Instruction::Mem(address, val));
+            } else {
+                return Err(ParseError::at(input, line, "a mask or a memory write").into());
+            }
+        }
+        Ok(instructions)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(run(input, ProgramType::Dma)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(run(input, ProgramType::Decoder)?.into())
+    }
+
+    fn samples(&self, part: Part) -> &'static [u8] {
+        match part {
+            Part::One => &[1],
+            // the first sample floats too many bits to decode
+            Part::Two => &[4],
+        }
+    }
+}
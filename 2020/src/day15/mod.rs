@@ -0,0 +1,69 @@
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day, ParseError};
+use eyre::Result;
+use std::collections::HashSet;
+
+/// The number spoken on turn `turn_count`, counting from 1, when the
+/// game starts with `initial_turns`, all different.
+pub fn nth_spoken(initial_turns: &[usize], turn_count: usize) -> usize {
+    if turn_count <= initial_turns.len() {
+        return initial_turns[turn_count - 1];
+    }
+    // the turn each number was last spoken on, or 0 if it hasn't been.
+    // Only starting numbers can be too big to fit, and those are never
+    // looked up again once they've been spoken.
+    let mut last_spoken_on = vec![0u32; turn_count];
+    let (&last, earlier) = initial_turns.split_last().expect("a starting number");
+    for (i, &n) in earlier.iter().enumerate() {
+        if n < turn_count {
+            last_spoken_on[n] = i as u32 + 1;
+        }
+    }
+
+    let mut last_spoken_number = last;
+    for turn in initial_turns.len()..turn_count {
+        let word_said = match last_spoken_on.get(last_spoken_number) {
+            Some(&before) if before > 0 => turn - before as usize,
+            _ => 0,
+        };
+        if last_spoken_number < turn_count {
+            last_spoken_on[last_spoken_number] = turn as u32;
+        }
+        //if turn % 500000 == 0 {
+        //println!("spoken number for {} is {}", turn, last_spoken_number);
+        //}
+        last_spoken_number = word_said;
+    }
+    last_spoken_number
+}
+
+pub struct Day15 {}
+
+register_day!(2020, 15, Day15 {});
+
+impl Day for Day15 {
+    type Input<'a> = Vec<usize>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        let mut said = HashSet::new();
+        Ok(input
+            .trim()
+            .split(',')
+            .map(|turn_str| {
+                let turn = number(input, turn_str)?;
+                if !said.insert(turn) {
+                    return Err(ParseError::at(input, turn_str, "a number not said yet"));
+                }
+                Ok(turn)
+            })
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(nth_spoken(input, 2020).into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(nth_spoken(input, 30000000).into())
+    }
+}
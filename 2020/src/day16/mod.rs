@@ -0,0 +1,211 @@
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day, ParseError, Part};
+use eyre::{eyre, Result};
+use std::collections::{HashMap, HashSet};
+use std::ops::RangeInclusive;
+
+/// A field, with the two ranges its values fall in.
+pub struct Rule<'a> {
+    pub name: &'a str,
+    pub ranges: [RangeInclusive<usize>; 2],
+}
+
+/// The ticket notes: the rules for each field, then the values on our
+/// ticket and on every nearby ticket.
+pub struct Notes<'a> {
+    pub rules: Vec<Rule<'a>>,
+    pub my_ticket: Vec<usize>,
+    pub nearby_tickets: Vec<Vec<usize>>,
+}
+
+pub fn parse_notes(input: &str) -> Result<Notes<'_>, ParseError> {
+    let mut sections = input.split("\n\n");
+    let rules = sections.next().unwrap_or_default();
+    let my_ticket = sections
+        .next()
+        .ok_or_else(|| ParseError::end(input, "your ticket"))?;
+    let nearby_tickets = sections
+        .next()
+        .ok_or_else(|| ParseError::end(input, "nearby tickets"))?;
+    //println!("{:?}, {:?}, {:?}", rules, my_ticket, nearby_tickets);
+
+    let rules = rules
+        .lines()
+        .map(|rule| {
+            let (name, ranges) = rule
+                .split_once(": ")
+                .ok_or_else(|| ParseError::at(input, rule, "a rule like \"class: 1-3 or 5-7\""))?;
+            let (range1, range2) = ranges
+                .split_once(" or ")
+                .ok_or_else(|| ParseError::at(input, ranges, "two ranges"))?;
+            Ok(Rule {
+                name,
+                ranges: [parse_range(input, range1)?, parse_range(input, range2)?],
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let my_ticket = my_ticket
+        .strip_prefix("your ticket:\n")
+        .ok_or_else(|| ParseError::at(input, my_ticket, "\"your ticket:\""))?;
+    let nearby_tickets = nearby_tickets
+        .strip_prefix("nearby tickets:\n")
+        .ok_or_else(|| ParseError::at(input, nearby_tickets, "\"nearby tickets:\""))?;
+    Ok(Notes {
+        my_ticket: parse_ticket(input, my_ticket.trim_end(), rules.len())?,
+        nearby_tickets: nearby_tickets
+            .lines()
+            .filter(|x| !x.is_empty())
+            .map(|tick| parse_ticket(input, tick, rules.len()))
+            .collect::<Result<_, _>>()?,
+        rules,
+    })
+}
+
+fn parse_range(input: &str, range: &str) -> Result<RangeInclusive<usize>, ParseError> {
+    let (low, high) = range
+        .split_once('-')
+        .ok_or_else(|| ParseError::at(input, range, "a range like 1-3"))?;
+    Ok(number(input, low)?..=number(input, high)?)
+}
+
+fn parse_ticket(input: &str, ticket: &str, fields: usize) -> Result<Vec<usize>, ParseError> {
+    let values = ticket
+        .split(',')
+        .map(|x| number(input, x))
+        .collect::<Result<Vec<_>, _>>()?;
+    if values.len() != fields {
+        return Err(ParseError::at(input, ticket, format!("{} values", fields)));
+    }
+    Ok(values)
+}
+
+/// Sum the invalid values on nearby tickets, and narrow down which fields
+/// each ticket position could be using the valid tickets.
+pub fn scan(notes: &Notes) -> Result<(usize, HashMap<usize, HashSet<String>>)> {
+    let mut all_fields = HashSet::new();
+
+    // all valid values in one set
+    let mut valid_values: HashSet<usize> = HashSet::new();
+    // valid values by key (field name)
+    let mut field_rules: HashMap<&str, HashSet<usize>> = HashMap::new();
+
+    for rule in &notes.rules {
+        let field_name = rule.name;
+        all_fields.insert(field_name.to_string());
+
+        // all ranges
+        let [range1, range2] = &rule.ranges;
+        let range1 = range1.clone().collect::<HashSet<usize>>();
+        let range2 = range2.clone().collect::<HashSet<usize>>();
+        valid_values.extend(range1.clone());
+        valid_values.extend(range2.clone());
+        field_rules.insert(field_name, range1.union(&range2).copied().collect());
+    }
+    //println!("all valid values: {:?}", valid_values);
+
+    let mut error_rate = 0;
+    let mut entry_field_map = HashMap::new();
+    // populate possible fields for each entry
+    for i in 0..notes.rules.len() {
+        entry_field_map.insert(i, all_fields.clone());
+    }
+
+    for nearby_ticket in &notes.nearby_tickets {
+        for (index, val) in nearby_ticket.iter().enumerate() {
+            if !valid_values.contains(val) {
+                error_rate += val;
+                continue;
+            }
+            //ticket may be valid... check if it breaks any rules
+            let possible_fields = entry_field_map.get_mut(&index).unwrap();
+            for (field_name, possible_values) in field_rules.iter() {
+                if !possible_values.contains(val) {
+                    possible_fields.remove(*field_name);
+                }
+            }
+        }
+    }
+    //println!("final error rate: {}", error_rate);
+    Ok((error_rate, entry_field_map))
+}
+
+pub fn departure_product(notes: &Notes) -> Result<usize> {
+    let (_, mut entry_field_map) = scan(notes)?;
+    let mut final_guesses: HashMap<usize, String> = HashMap::new();
+
+    while final_guesses.len() < entry_field_map.len() {
+        let guessed = final_guesses.len();
+        //println!("intermediate guesses: {:?}", entry_field_map);
+        //println!("final guesses: {:?}", final_guesses);
+        for (field_index, possible_fields) in entry_field_map.iter_mut() {
+            //println!("checking {} {:?}", field_index, possible_fields);
+            // if one of the possible fields has already been guessed,
+            // remove it from the list of possible fields.
+            for field_name in possible_fields.clone() {
+                if final_guesses.values().any(|x| **x == field_name) {
+                    //println!(
+                    //    "already ffound the answer for {}:{}",
+                    //    field_index, field_name
+                    //);
+                    possible_fields.remove(&field_name);
+                }
+            }
+            // if there is only one guess left, this is the right field!
+            if possible_fields.len() == 1 {
+                //println!("only one guess left here! {:?}", possible_fields);
+                let final_field = &possible_fields.drain().collect::<Vec<String>>()[0];
+                final_guesses.insert(*field_index, final_field.to_string());
+            }
+        }
+        if final_guesses.len() == guessed {
+            let mut unknown: Vec<_> = entry_field_map
+                .keys()
+                .filter(|index| !final_guesses.contains_key(index))
+                .collect();
+            unknown.sort_unstable();
+            return Err(eyre!(
+                "can't tell which field is in positions {:?}",
+                unknown
+            ));
+        }
+    }
+    //println!("final guesses: {:?}", final_guesses);
+
+    let mut departure_sum = 1;
+    for (index, &entry) in notes.my_ticket.iter().enumerate() {
+        let field_name = final_guesses.get(&index).unwrap();
+        //println!("{}:{:?}", field_name, entry);
+        if field_name.contains("departure") {
+            departure_sum *= entry;
+        }
+    }
+    //println!("final departure sum: {}", departure_sum);
+    Ok(departure_sum)
+}
+
+pub struct Day16 {}
+
+register_day!(2020, 16, Day16 {});
+
+impl Day for Day16 {
+    type Input<'a> = Notes<'a>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(parse_notes(input)?)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(scan(input)?.0.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(departure_product(input)?.into())
+    }
+
+    fn samples(&self, part: Part) -> &'static [u8] {
+        match part {
+            Part::One => &[1],
+            Part::Two => &[2],
+        }
+    }
+}
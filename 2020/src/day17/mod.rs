@@ -0,0 +1,106 @@
+use aoc_core::automaton::{Automaton, Cube, LifeRule, Neighborhood};
+use aoc_core::day::no_such_option;
+use aoc_core::grid::{FromChar, Grid};
+use aoc_core::{register_day, Answer, Day};
+use eyre::Result;
+
+/// One cube of the starting slice.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum State {
+    Active,
+    Inactive,
+}
+
+impl FromChar for State {
+    const EXPECTED: &'static str = "'#' or '.'";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '#' => Some(State::Active),
+            '.' => Some(State::Inactive),
+            _ => None,
+        }
+    }
+}
+
+/// Active cubes stay active with 2 or 3 active neighbors, inactive ones
+/// become active with exactly 3.
+pub const RULE: &str = "B3/S23";
+
+/// Boot a pocket dimension with `N` dimensions from a 2d starting slice,
+/// returning how many cubes are active after `cycle_count` cycles.
+///
+/// The slice lies along the first two axes, with every other coordinate 0.
+pub fn boot<const N: usize>(
+    slice: &Grid<State>,
+    rule: &LifeRule,
+    cycle_count: usize,
+) -> Result<usize> {
+    rule.check_neighborhood(&[Neighborhood::Moore, Neighborhood::VonNeumann])?;
+    rule.check_unbounded()?;
+    assert!(N >= 2, "the starting slice needs at least 2 dimensions");
+    let active = slice
+        .iter()
+        .filter(|(_, &state)| state == State::Active)
+        .map(|((x, y), _)| {
+            let mut node = [0; N];
+            node[0] = x as isize;
+            node[1] = y as isize;
+            node
+        });
+    let space = match rule.neighborhood {
+        Neighborhood::VonNeumann => Cube::<N>::von_neumann(),
+        _ => Cube::<N>::new(),
+    };
+    let mut map = Automaton::new(space, rule.clone(), active);
+    //println!("starting with: {}", map.count_alive());
+    map.run(cycle_count);
+    //println!("result: {}", map.count_alive());
+    Ok(map.count_alive())
+}
+
+pub fn part1(slice: &Grid<State>, rule: &LifeRule) -> Result<usize> {
+    boot::<3>(slice, rule, 6)
+}
+
+pub fn part2(slice: &Grid<State>, rule: &LifeRule) -> Result<usize> {
+    boot::<4>(slice, rule, 6)
+}
+
+pub struct Day17 {
+    rule: LifeRule,
+}
+
+impl Default for Day17 {
+    fn default() -> Self {
+        Day17 {
+            rule: RULE.parse().expect("the puzzle's rule parses"),
+        }
+    }
+}
+
+register_day!(2020, 17, Day17::default());
+
+impl Day for Day17 {
+    type Input<'a> = Grid<State>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part1(input, &self.rule)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part2(input, &self.rule)?.into())
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "rule" => self.rule = value.parse()?,
+            _ => return Err(no_such_option(key)),
+        }
+        Ok(())
+    }
+}
@@ -1,7 +1,7 @@
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day, ParseError};
 use eyre::Result;
-use regex::Regex;
 use std::collections::VecDeque;
-use std::fs::read_to_string;
 
 #[derive(Debug, Clone, PartialEq)]
 enum TokenType {
@@ -10,8 +10,6 @@ enum TokenType {
     Num,
     LeftParen,
     RightParen,
-    Space,
-    Invalid,
 }
 
 #[derive(Debug, Clone)]
@@ -23,12 +21,10 @@ struct Token {
 impl Token {
     fn new(tok_str: String) -> Token {
         let token_type = match tok_str.as_str() {
-            " " => TokenType::Space,
             "+" => TokenType::Plus,
             "*" => TokenType::Mult,
             "(" => TokenType::LeftParen,
             ")" => TokenType::RightParen,
-            "\n" => TokenType::Invalid,
             _ => TokenType::Num,
         };
         Token {
@@ -38,56 +34,69 @@ impl Token {
     }
 }
 
-enum EquationPrecedence {
+/// Split `expr`, a line of `input`, into tokens, checking that numbers and
+/// operators alternate and the parentheses balance.
+fn tokenize(input: &str, expr: &str) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = vec![];
+    let mut expect_operand = true;
+    let mut depth = 0;
+    let mut chars = expr.char_indices().peekable();
+    while let Some((i, ch)) = chars.next() {
+        let mut end = i + ch.len_utf8();
+        if ch.is_ascii_digit() {
+            while let Some(&(j, next)) = chars.peek() {
+                if !next.is_ascii_digit() {
+                    break;
+                }
+                end = j + 1;
+                chars.next();
+            }
+        }
+        let text = &expr[i..end];
+        match (expect_operand, ch) {
+            (_, ' ') => continue,
+            (true, '0'..='9') => {
+                number::<u64>(input, text)?;
+                expect_operand = false;
+            }
+            (true, '(') => depth += 1,
+            (false, '+') | (false, '*') => expect_operand = true,
+            (false, ')') if depth > 0 => depth -= 1,
+            (true, _) => return Err(ParseError::at(input, text, "a number or '('")),
+            (false, _) => return Err(ParseError::at(input, text, "an operator or ')'")),
+        }
+        tokens.push(Token::new(text.to_string()));
+    }
+    if expect_operand {
+        return Err(ParseError::after(input, expr, "a number"));
+    }
+    if depth > 0 {
+        return Err(ParseError::after(input, expr, "')'"));
+    }
+    Ok(tokens)
+}
+
+/// How `+` and `*` bind relative to each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Precedence {
     Equal,    // all ops have equal precedence
     AddFirst, // addition before multiplication
 }
 
 struct Equation {
     tokens: Vec<Token>,
-    precedence: EquationPrecedence,
+    precedence: Precedence,
 }
 
 impl Equation {
-    fn new(precedence: EquationPrecedence, input_str: &str) -> Result<Self> {
-        let mut tokens = vec![];
-        let re = Regex::new(r"([\(]+)([0-9]*)|([0-9]*)([\)]+)")?;
-        for tok_str in input_str.split_whitespace() {
-            // only try to make it complicated if there is a sequence of parens and numbers
-            if let Some(caps) = re.captures(tok_str) {
-                //println!(
-                //    "captures: {:?}",
-                //    caps.iter().collect::<Vec<Option<regex::Match>>>()
-                //);
-                if let Some(l_parens) = caps.get(1) {
-                    //println!("found l parens {:?}", l_parens.as_str());
-                    for _ in 0..l_parens.as_str().len() {
-                        tokens.push(Token::new("(".to_string()));
-                    }
-                }
-                if let Some(num) = caps.get(2) {
-                    tokens.push(Token::new(num.as_str().to_string()));
-                }
-                if let Some(num) = caps.get(3) {
-                    tokens.push(Token::new(num.as_str().to_string()));
-                }
-                if let Some(r_parens) = caps.get(4) {
-                    //println!("found r parens {:?}", r_parens.as_str());
-                    for _ in 0..r_parens.as_str().len() {
-                        tokens.push(Token::new(")".to_string()));
-                    }
-                }
-            } else {
-                tokens.push(Token::new(tok_str.to_string()));
-            }
-        }
-        Ok(Equation { tokens, precedence })
+    fn new(precedence: Precedence, tokens: Vec<Token>) -> Self {
+        Equation { tokens, precedence }
     }
 
     fn has_greater_precedence_than(&self, token_type: &TokenType, other: &TokenType) -> bool {
         match self.precedence {
-            EquationPrecedence::Equal => true,
-            EquationPrecedence::AddFirst => token_type == &TokenType::Plus || token_type == other,
+            Precedence::Equal => true,
+            Precedence::AddFirst => token_type == &TokenType::Plus || token_type == other,
         }
     }
 
@@ -128,10 +137,9 @@ impl Equation {
                         op_stack.pop();
                     }
                 }
-                _ => (),
             }
         }
-        while op_stack.is_empty() {
+        while !op_stack.is_empty() {
             output_queue.push_back(op_stack.pop().unwrap().clone());
         }
         //println!(
@@ -171,31 +179,45 @@ impl Equation {
     }
 }
 
-fn main() -> Result<()> {
-    let input = read_to_string("src/day18/input.txt")?;
+/// Evaluate a single expression, e.g. `evaluate("1 + 2 * 3", Precedence::AddFirst)`.
+pub fn evaluate(expr: &str, precedence: Precedence) -> Result<u64> {
+    let equation = Equation::new(precedence, tokenize(expr, expr)?);
+    let result = equation.eval();
+    //println!("got result: {}", result.value);
+    Ok(result.value.parse::<u64>()?)
+}
+
+/// The sum of every expression in the homework, one per line.
+pub fn sum_results(input: &str, precedence: Precedence) -> Result<u64> {
     let mut sum_result = 0;
     for line in input.split('\n') {
         if line.is_empty() {
             continue;
         }
-        let equation = Equation::new(EquationPrecedence::Equal, &line)?;
-        let result = equation.eval();
-        //println!("got result: {}", result.value);
-        sum_result += result.value.parse::<u64>()?;
+        sum_result += evaluate(line, precedence)?;
     }
-    println!("final sum result: {}", sum_result);
+    Ok(sum_result)
+}
 
-    println!("part2");
-    let mut sum_result = 0;
-    for line in input.split('\n') {
-        if line.is_empty() {
-            continue;
+pub struct Day18 {}
+
+register_day!(2020, 18, Day18 {});
+
+impl Day for Day18 {
+    type Input<'a> = &'a str;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        for line in input.lines().filter(|line| !line.is_empty()) {
+            tokenize(input, line)?;
         }
-        let equation = Equation::new(EquationPrecedence::AddFirst, &line)?;
-        let result = equation.eval();
-        //println!("got result: {}", result.value);
-        sum_result += result.value.parse::<u64>()?;
+        Ok(input)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(sum_results(input, Precedence::Equal)?.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(sum_results(input, Precedence::AddFirst)?.into())
     }
-    println!("final sum result: {}", sum_result);
-    Ok(())
 }
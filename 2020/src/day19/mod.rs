@@ -0,0 +1,164 @@
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day, ParseError, Part};
+use eyre::Result;
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub enum Rule {
+    Ch(String),
+    List(Vec<usize>),
+    Or(Vec<usize>, Vec<usize>),
+}
+
+#[derive(Clone)]
+pub struct RuleSet {
+    rules: HashMap<usize, Rule>,
+}
+
+impl RuleSet {
+    pub fn new(rules_str: &str) -> Result<Self, ParseError> {
+        let raw_rules: Vec<&str> = rules_str.split('\n').filter(|x| !x.is_empty()).collect();
+        let mut rules = HashMap::new();
+        let mut references = vec![];
+        for rule_str in raw_rules {
+            let (number_str, body) = rule_str
+                .split_once(": ")
+                .ok_or_else(|| ParseError::at(rules_str, rule_str, "a rule like \"0: 4 1 5\""))?;
+            let rule_number = number(rules_str, number_str)?;
+            //println!("captured rule number {}: {:?}", rule_number, body);
+
+            let rule =
+                if let Some(str_rule) = body.strip_prefix('"').and_then(|b| b.strip_suffix('"')) {
+                    //println!("string rule: {}", str_rule);
+                    Rule::Ch(str_rule.to_string())
+                } else if let Some((list_1, list_2)) = body.split_once(" | ") {
+                    let rule_list_1 = rule_list(rules_str, list_1, &mut references)?;
+                    let rule_list_2 = rule_list(rules_str, list_2, &mut references)?;
+                    //println!("rule list or: {:?} or {:?}", rule_list_1, rule_list_2);
+                    Rule::Or(rule_list_1, rule_list_2)
+                } else {
+                    let rule_list = rule_list(rules_str, body, &mut references)?;
+                    //println!("rule list: {:?}", rule_list);
+                    Rule::List(rule_list)
+                };
+            rules.insert(rule_number, rule);
+        }
+        if !rules.contains_key(&0) {
+            return Err(ParseError::end(rules_str, "a rule 0"));
+        }
+        if let Some((_, text)) = references.iter().find(|(n, _)| !rules.contains_key(n)) {
+            return Err(ParseError::at(rules_str, text, "the number of a rule"));
+        }
+        //println!("finished parsing rules: {:#?}", rules);
+        Ok(RuleSet { rules })
+    }
+
+    fn _solve_word(&self, word: String, rule: &Rule) -> HashSet<String> {
+        let result = match rule {
+            Rule::Ch(val) => word
+                .strip_prefix(val)
+                .map(|w| {
+                    let mut h = HashSet::new();
+                    h.insert(w.to_string());
+                    h
+                })
+                .unwrap_or_else(HashSet::new),
+            Rule::List(rule_list) => {
+                let mut result = HashSet::new();
+                result.insert(word);
+                for rule in rule_list {
+                    let mut new_result = HashSet::new();
+                    for message in result {
+                        new_result = new_result
+                            .union(&self._solve_word(message, self.rules.get(rule).unwrap()))
+                            .map(|x| x.to_string())
+                            .collect();
+                    }
+                    result = new_result;
+                }
+                result
+            }
+            Rule::Or(rule_list_1, rule_list_2) => {
+                let result = self._solve_word(word.clone(), &Rule::List(rule_list_1.clone()));
+                let result2 = self._solve_word(word, &Rule::List(rule_list_2.clone()));
+                result.union(&result2).cloned().collect()
+            }
+        };
+        result
+    }
+
+    fn solve_word(&self, word: String, rule_num: usize) -> HashSet<String> {
+        let current_rule = self.rules.get(&rule_num).unwrap();
+        self._solve_word(word, current_rule)
+    }
+}
+
+/// Parse a space separated list of rule numbers, noting where each one
+/// was so that references to missing rules can be reported.
+fn rule_list<'a>(
+    input: &str,
+    list: &'a str,
+    references: &mut Vec<(usize, &'a str)>,
+) -> Result<Vec<usize>, ParseError> {
+    list.split(' ')
+        .map(|x| {
+            let n = number(input, x)?;
+            references.push((n, x));
+            Ok(n)
+        })
+        .collect()
+}
+
+/// The rules and the messages to check against them.
+pub struct Messages<'a> {
+    pub rules: RuleSet,
+    pub messages: Vec<&'a str>,
+}
+
+pub fn count_matching(rule_set: &RuleSet, messages: &[&str]) -> usize {
+    messages
+        .iter()
+        .flat_map(|message| rule_set.solve_word(message.to_string(), 0))
+        .filter(|x| x.is_empty())
+        .count()
+}
+
+pub struct Day19 {}
+
+register_day!(2020, 19, Day19 {});
+
+impl Day for Day19 {
+    type Input<'a> = Messages<'a>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        let (rules, messages) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::end(input, "a blank line, then messages"))?;
+        // the rules come first, so positions in them are positions in the input
+        let rules = RuleSet::new(rules)?;
+        let messages = messages.split('\n').filter(|x| !x.is_empty()).collect();
+        Ok(Messages { rules, messages })
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(count_matching(&input.rules, &input.messages).into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        let mut rule_set = input.rules.clone();
+        // replace rules 8 and 11 with looping versions
+        rule_set.rules.insert(8, Rule::Or(vec![42], vec![42, 8]));
+        rule_set
+            .rules
+            .insert(11, Rule::Or(vec![42, 31], vec![42, 11, 31]));
+        Ok(count_matching(&rule_set, &input.messages).into())
+    }
+
+    fn samples(&self, part: Part) -> &'static [u8] {
+        match part {
+            Part::One => &[1, 3],
+            // the first sample has no rules 8 or 11 to replace
+            Part::Two => &[3],
+        }
+    }
+}
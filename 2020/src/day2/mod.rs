@@ -0,0 +1,92 @@
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day, ParseError};
+use eyre::Result;
+use std::vec::Vec;
+
+pub struct Day2 {}
+
+register_day!(2020, 2, Day2 {});
+
+/// A password along with the policy it was created under.
+pub struct Entry<'a> {
+    range: Vec<i32>,
+    ch: char,
+    password: &'a str,
+}
+
+impl Day for Day2 {
+    type Input<'a> = Vec<Entry<'a>>;
+
+    fn parse<'a>(&self, s: &'a str) -> Result<Self::Input<'a>> {
+        let lines = s.split('\n');
+
+        let mut entries = vec![];
+        for line in lines {
+            if line.is_empty() {
+                continue;
+            }
+            let (policy, password) = line
+                .split_once(": ")
+                .ok_or_else(|| ParseError::at(s, line, "a policy and password"))?;
+            let (range, letter) = policy
+                .split_once(' ')
+                .ok_or_else(|| ParseError::after(s, policy, "a letter"))?;
+            let (low, high) = range
+                .split_once('-')
+                .ok_or_else(|| ParseError::at(s, range, "a range like 1-3"))?;
+            let range = vec![number(s, low)?, number(s, high)?];
+            let mut chars = letter.chars();
+            let ch = match (chars.next(), chars.next()) {
+                (Some(ch), None) => ch,
+                _ => return Err(ParseError::at(s, letter, "a single letter").into()),
+            };
+            entries.push(Entry {
+                range,
+                ch,
+                password,
+            });
+        }
+        Ok(entries)
+    }
+
+    fn part_1(&self, entries: &Self::Input<'_>) -> Result<Answer> {
+        let mut valid_count = 0;
+        for Entry {
+            range,
+            ch,
+            password,
+        } in entries
+        {
+            let occurence_count = password.matches(*ch).count();
+            if (range[0]..range[1] + 1).contains(&(occurence_count as i32)) {
+                //println!("found valid");
+                valid_count += 1;
+            } else {
+                //println!(
+                //"{} was invalid. {} not in range {}..{}",
+                //password, ch, range[0], range[1]
+                //);
+            }
+            //println!("occurences: {}", occurence_count);
+        }
+        Ok(valid_count.into())
+    }
+
+    fn part_2(&self, entries: &Self::Input<'_>) -> Result<Answer> {
+        let mut valid_count = 0;
+        for Entry {
+            range,
+            ch,
+            password,
+        } in entries
+        {
+            let in_pos_1 = password.chars().take(range[0] as usize).last().unwrap() == *ch;
+            let in_pos_2 = password.chars().take(range[1] as usize).last().unwrap() == *ch;
+
+            if in_pos_1 && !in_pos_2 || !in_pos_1 && in_pos_2 {
+                valid_count += 1;
+            }
+        }
+        Ok(valid_count.into())
+    }
+}
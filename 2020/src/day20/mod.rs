@@ -1,34 +1,57 @@
-use eyre::Result;
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day, ParseError};
+use eyre::{eyre, Result};
 use std::cmp;
 use std::collections::{HashMap, HashSet};
-use std::fs::read_to_string;
 
 const PATTERN: &str = "                  # 
 #    ##    ##    ###
  #  #  #  #  #  #   ";
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone)]
-struct Tile {
+pub struct Tile {
     number: usize,
     vals: Vec<Vec<bool>>,
     borders: Vec<Vec<bool>>,
 }
 
 impl Tile {
-    fn from_raw(raw_input: &str) -> Self {
+    /// Parse a tile from `raw_input`, a section of `input`.
+    pub fn from_raw(input: &str, raw_input: &str) -> Result<Self, ParseError> {
         let mut lines = raw_input.trim().lines();
-        let number = lines
-            .next()
-            .unwrap()
+        let header = lines.next().unwrap_or_default();
+        let tile_number = header
             .strip_prefix("Tile ")
-            .unwrap()
-            .strip_suffix(":")
-            .map(|s| s.parse::<usize>().unwrap())
-            .unwrap();
-        let vals: Vec<Vec<bool>> = lines
-            .map(|line| line.trim().chars().map(|c| c == '#').collect::<Vec<_>>())
-            .collect();
-        Self::new(number, vals)
+            .and_then(|s| s.strip_suffix(':'))
+            .ok_or_else(|| ParseError::at(input, header, "a header like \"Tile 2311:\""))?;
+        let number = number(input, tile_number)?;
+        let mut vals: Vec<Vec<bool>> = vec![];
+        for line in lines.map(str::trim) {
+            if let Some(i) = line.find(|c| c != '#' && c != '.') {
+                let ch = line[i..].chars().next().unwrap();
+                return Err(ParseError::at(
+                    input,
+                    &line[i..i + ch.len_utf8()],
+                    "'#' or '.'",
+                ));
+            }
+            if line.len() != vals.first().map_or(line.len(), Vec::len) || line.is_empty() {
+                return Err(ParseError::at(
+                    input,
+                    line,
+                    "a row as wide as the tile's first",
+                ));
+            }
+            vals.push(line.chars().map(|c| c == '#').collect());
+        }
+        if vals.len() < 3 || vals.len() != vals[0].len() {
+            return Err(ParseError::after(
+                input,
+                raw_input.trim_end(),
+                "a square tile at least 3 rows high",
+            ));
+        }
+        Ok(Self::new(number, vals))
     }
 
     fn new(number: usize, vals: Vec<Vec<bool>>) -> Self {
@@ -109,17 +132,21 @@ fn rotate_vals(vals: &[Vec<bool>]) -> Vec<Vec<bool>> {
     rotated_vals
 }
 
-fn compose_image(tile_map: &HashMap<&Tile, HashSet<&Tile>>) -> Vec<Vec<bool>> {
-    let structure = compose_board_structure(&tile_map);
-    for row in &structure {
-        println!();
-        for ch in row {
-            print!("{} ", ch.number);
-        }
+pub fn compose_image(tile_map: &HashMap<&Tile, HashSet<&Tile>>) -> Result<Vec<Vec<bool>>> {
+    let structure = compose_board_structure(tile_map)?;
+    let side = structure.len();
+    if side * side != tile_map.len() || structure.iter().any(|row| row.len() != side) {
+        return Err(eyre!("the tiles don't fit together in a square"));
     }
-    let board = orient_tiles(&structure);
+    //for row in &structure {
+    //println!();
+    //for ch in row {
+    //print!("{} ", ch.number);
+    //}
+    //}
+    let board = orient_tiles(&structure)?;
 
-    board.into_iter().fold(vec![], |mut full, row| {
+    Ok(board.into_iter().fold(vec![], |mut full, row| {
         let n_r = row[0].vals_without_border().len();
         let contents = row
             .into_iter()
@@ -134,25 +161,27 @@ fn compose_image(tile_map: &HashMap<&Tile, HashSet<&Tile>>) -> Vec<Vec<bool>> {
             full.push(c);
         }
         full
-    })
+    }))
 }
 
-fn orient_tiles(structure: &[Vec<&Tile>]) -> Vec<Vec<Tile>> {
+fn orient_tiles(structure: &[Vec<&Tile>]) -> Result<Vec<Vec<Tile>>> {
     let mut first_tile = structure[0][0].clone();
-    if let Some(oriented) = compose_board(&structure, &first_tile) {
-        return oriented;
+    if let Some(oriented) = compose_board(structure, &first_tile) {
+        return Ok(oriented);
     }
 
     for _ in 0..2 {
         first_tile = first_tile.flip();
         for _ in 0..4 {
             first_tile = first_tile.rotate();
-            if let Some(oriented) = compose_board(&structure, &first_tile) {
-                return oriented;
+            if let Some(oriented) = compose_board(structure, &first_tile) {
+                return Ok(oriented);
             }
         }
     }
-    panic!();
+    Err(eyre!(
+        "the tiles can't be turned so that all their edges line up"
+    ))
 }
 
 fn compose_board(structure: &[Vec<&Tile>], first_tile: &Tile) -> Option<Vec<Vec<Tile>>> {
@@ -172,7 +201,7 @@ fn compose_board(structure: &[Vec<&Tile>], first_tile: &Tile) -> Option<Vec<Vec<
                     for _ in 0..4 {
                         tile = tile.rotate();
                         let (first_rotation, second_rotation, flip) =
-                            board[i - 1][j].matched_borders(&tile).unwrap();
+                            board[i - 1][j].matched_borders(&tile)?;
                         if first_rotation == 2 && second_rotation == 0 && !flip {
                             matched = true;
                             break;
@@ -193,7 +222,7 @@ fn compose_board(structure: &[Vec<&Tile>], first_tile: &Tile) -> Option<Vec<Vec<
                     for _ in 0..4 {
                         tile = tile.rotate();
                         let (first_rotation, second_rotation, flip) =
-                            row[j - 1].matched_borders(&tile).unwrap();
+                            row[j - 1].matched_borders(&tile)?;
                         if first_rotation == 1 && second_rotation == 3 && !flip {
                             matched = true;
                             break;
@@ -216,7 +245,7 @@ fn compose_board(structure: &[Vec<&Tile>], first_tile: &Tile) -> Option<Vec<Vec<
 
 fn compose_board_structure<'a>(
     tile_map: &HashMap<&'a Tile, HashSet<&'a Tile>>,
-) -> Vec<Vec<&'a Tile>> {
+) -> Result<Vec<Vec<&'a Tile>>> {
     let mut chosen = HashSet::new();
     let mut board = vec![];
     let first_corner = tile_map
@@ -224,7 +253,7 @@ fn compose_board_structure<'a>(
         .filter(|(_, m)| m.len() == 2)
         .map(|(t, _)| t)
         .next()
-        .unwrap();
+        .ok_or_else(|| eyre!("no tile is a corner"))?;
     let mut row = vec![];
     let mut current = first_corner;
 
@@ -274,14 +303,14 @@ fn compose_board_structure<'a>(
         board.push(row);
     }
 
-    board
+    Ok(board)
 }
 
 fn find_pattern(image: &[Vec<bool>], pattern: &[Vec<bool>]) -> usize {
     let mut image = image.to_owned();
     let mut any_matched = false;
-    for i in 0..(image.len() - pattern.len()) {
-        for j in 0..(image[0].len() - pattern[0].len()) {
+    for i in 0..image.len().saturating_sub(pattern.len()) {
+        for j in 0..image[0].len().saturating_sub(pattern[0].len()) {
             let mut matched = true;
 
             for k in 0..pattern.len() {
@@ -322,67 +351,98 @@ fn find_pattern(image: &[Vec<bool>], pattern: &[Vec<bool>]) -> usize {
         .sum()
 }
 
-fn main() -> Result<()> {
-    let input = read_to_string("src/day20/input.txt")?;
-    let tiles: Vec<_> = input
-        .trim()
-        .split("\n\n")
-        .map(|raw_str| Tile::from_raw(raw_str))
-        .collect();
-    //println!("tiles: {:?}", tiles);
-
+pub fn match_tiles(tiles: &[Tile]) -> HashMap<&Tile, HashSet<&Tile>> {
     let mut matches = HashMap::new();
-    for tile in &tiles {
+    for tile in tiles {
         for tile2 in tiles.iter().skip_while(|t| *t != tile).skip(1) {
-            if tile.matches_with(&tile2) {
+            if tile.matches_with(tile2) {
                 matches
                     .entry(tile)
                     .or_insert_with(HashSet::new)
                     .insert(tile2);
                 matches
-                    .entry(&tile2)
+                    .entry(tile2)
                     .or_insert_with(HashSet::new)
                     .insert(tile);
             }
         }
     }
     //println!("matches: {:?}", matches.values().collect::<Vec<_>>());
-    // part 1
-    let corners: usize = matches
-        .clone()
-        .iter()
-        .filter(|(_, m)| {
-            //println!("{}:{}", t.number, m.len());
-            m.len() == 2
-        })
-        .map(|(t, _)| t.number)
-        .product();
-    println!("corners product: {:?}", corners);
-
-    // part 2
-    let image = compose_image(&matches);
-    for row in &image {
-        for &c in row {
-            let ch = if c { '#' } else { '.' };
-            print!("{}", ch);
+    matches
+}
+
+pub struct Day20 {}
+
+register_day!(2020, 20, Day20 {});
+
+impl Day for Day20 {
+    type Input<'a> = Vec<Tile>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        let raw_tiles: Vec<_> = input.trim().split("\n\n").collect();
+        let tiles: Vec<Tile> = raw_tiles
+            .iter()
+            .map(|raw| Tile::from_raw(input, raw))
+            .collect::<Result<_, _>>()?;
+        //println!("tiles: {:?}", tiles);
+        let size = tiles[0].vals.len();
+        if let Some(i) = tiles.iter().position(|tile| tile.vals.len() != size) {
+            let expected = format!("a tile {} rows high, like the first", size);
+            return Err(ParseError::at(input, raw_tiles[i].trim(), expected).into());
+        }
+        let side = (1..)
+            .find(|side| side * side >= tiles.len())
+            .unwrap_or_default();
+        if side * side != tiles.len() {
+            let expected = format!("{} tiles to make a square", side * side);
+            return Err(ParseError::end(input, expected).into());
         }
-        println!();
+        Ok(tiles)
     }
 
-    let mut pattern: Vec<_> = PATTERN
-        .lines()
-        .map(|row| row.chars().map(|c| c == '#').collect::<Vec<_>>())
-        .collect();
-    let mut final_answer = 0;
-    for _ in 0..2 {
-        pattern = flip_vals(&pattern);
-        for _ in 0..4 {
-            pattern = rotate_vals(&pattern);
-            let tmp = find_pattern(&image, &pattern);
-            final_answer = cmp::max(final_answer, tmp);
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        let matches = match_tiles(input);
+        let corners = matches
+            .iter()
+            .filter(|(_, m)| {
+                //println!("{}:{}", t.number, m.len());
+                m.len() == 2
+            })
+            .map(|(t, _)| t.number)
+            .collect::<Vec<_>>();
+        if corners.len() != 4 {
+            return Err(eyre!("expected 4 corner tiles, found {}", corners.len()));
         }
+        Ok(corners.into_iter().product::<usize>().into())
     }
-    println!("final answer: {}", final_answer);
 
-    Ok(())
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        let matches = match_tiles(input);
+        if let Some(alone) = input.iter().find(|tile| !matches.contains_key(tile)) {
+            return Err(eyre!("tile {} doesn't fit any other tile", alone.number));
+        }
+        let image = compose_image(&matches)?;
+        //for row in &image {
+        //for &c in row {
+        //let ch = if c { '#' } else { '.' };
+        //print!("{}", ch);
+        //}
+        //println!();
+        //}
+
+        let mut pattern: Vec<_> = PATTERN
+            .lines()
+            .map(|row| row.chars().map(|c| c == '#').collect::<Vec<_>>())
+            .collect();
+        let mut final_answer = 0;
+        for _ in 0..2 {
+            pattern = flip_vals(&pattern);
+            for _ in 0..4 {
+                pattern = rotate_vals(&pattern);
+                let tmp = find_pattern(&image, &pattern);
+                final_answer = cmp::max(final_answer, tmp);
+            }
+        }
+        Ok(final_answer.into())
+    }
 }
@@ -1,25 +1,17 @@
+use aoc_core::{register_day, Answer, Day, ParseError};
 use eyre::Result;
-use lazy_static::lazy_static;
-use regex::Regex;
 use std::collections::{HashMap, HashSet};
-use std::fs::read_to_string;
-
-lazy_static! {
-    static ref ING_REGEX: Regex = Regex::new(r"((?:\w+ )+)(?:\(contains (.*)+\))").unwrap();
-}
 
 #[derive(Debug)]
 struct AllergenListing {
-    name: String,
     entry_count: usize,
     // name -> count (for this ingredient)
     ingredient_lists: HashMap<String, usize>,
 }
 
 impl AllergenListing {
-    fn new(name: &str) -> Self {
+    fn new() -> Self {
         AllergenListing {
-            name: name.to_string(),
             entry_count: 0,
             ingredient_lists: HashMap::new(),
         }
@@ -38,58 +30,49 @@ impl IngredientListing {
     }
 }
 
-fn read_raw_to_ingredient_list(
-    ingredient_listings: &mut Vec<IngredientListing>,
-    raw_listing: &str,
-) {
-    let caps = ING_REGEX.captures(raw_listing).unwrap();
-    let names: HashSet<String> = caps
-        .get(1)
-        .unwrap()
-        .as_str()
-        .trim()
-        .split_whitespace()
-        .map(|x| x.to_string())
-        .collect();
-    let potential_allergens: HashSet<String> = caps
-        .get(2)
-        .unwrap()
-        .as_str()
+/// A food's ingredients and some of the allergens they contain.
+pub struct Food<'a> {
+    pub ingredients: Vec<&'a str>,
+    pub allergens: Vec<&'a str>,
+}
+
+pub fn parse_foods(input: &str) -> Result<Vec<Food<'_>>, ParseError> {
+    input
         .trim()
-        .split(',')
-        .map(|x| x.trim().to_string())
-        .collect();
+        .lines()
+        .map(|line| {
+            let (ingredients, allergens) = line
+                .split_once(" (contains ")
+                .and_then(|(ingredients, rest)| Some((ingredients, rest.strip_suffix(')')?)))
+                .ok_or_else(|| {
+                    ParseError::at(input, line, "ingredients followed by \"(contains ...)\"")
+                })?;
+            Ok(Food {
+                ingredients: ingredients.split_whitespace().collect(),
+                allergens: allergens.split(',').map(|x| x.trim()).collect(),
+            })
+        })
+        .collect()
+}
+
+fn read_raw_to_ingredient_list(ingredient_listings: &mut Vec<IngredientListing>, food: &Food) {
+    let names: HashSet<String> = food.ingredients.iter().map(|x| x.to_string()).collect();
+    let potential_allergens: HashSet<String> =
+        food.allergens.iter().map(|x| x.to_string()).collect();
 
     ingredient_listings.push(IngredientListing::new(names, potential_allergens));
 }
-fn read_raw_to_allergen_map(
-    allergens_map: &mut HashMap<String, AllergenListing>,
-    raw_listing: &str,
-) {
-    let caps = ING_REGEX.captures(raw_listing).unwrap();
-    let names: Vec<&str> = caps
-        .get(1)
-        .unwrap()
-        .as_str()
-        .trim()
-        .split_whitespace()
-        .collect();
-    let potential_allergens: Vec<&str> = caps
-        .get(2)
-        .unwrap()
-        .as_str()
-        .trim()
-        .split(',')
-        .map(|x| x.trim())
-        .collect();
+fn read_raw_to_allergen_map(allergens_map: &mut HashMap<String, AllergenListing>, food: &Food) {
+    let names = &food.ingredients;
+    let potential_allergens = &food.allergens;
     //println!("names: {:?}, allergens: {:?}", names, potential_allergens);
 
-    for allergen in &potential_allergens {
+    for allergen in potential_allergens {
         let allergen_entry = allergens_map
             .entry(allergen.to_string())
-            .or_insert_with(|| AllergenListing::new(allergen));
+            .or_insert_with(AllergenListing::new);
         allergen_entry.entry_count += 1;
-        for ingredient_name in &names {
+        for ingredient_name in names {
             *allergen_entry
                 .ingredient_lists
                 .entry(ingredient_name.to_string())
@@ -98,18 +81,15 @@ fn read_raw_to_allergen_map(
     }
 }
 
-fn main() -> Result<()> {
-    let input = read_to_string("src/day21/input.txt")?;
-    //let input = read_to_string("src/day21/input-sample.txt")?;
-
+pub fn part1(foods: &[Food]) -> usize {
     let mut all_allergens = HashMap::new();
-    for line in input.trim().lines() {
-        read_raw_to_allergen_map(&mut all_allergens, &line);
+    for food in foods {
+        read_raw_to_allergen_map(&mut all_allergens, food);
     }
 
     let all_ingredients = all_allergens
-        .iter()
-        .map(|(_, entry)| entry.ingredient_lists.keys().collect::<HashSet<&String>>())
+        .values()
+        .map(|entry| entry.ingredient_lists.keys().collect::<HashSet<&String>>())
         .fold(HashSet::new(), |res, ing_list| {
             res.union(&ing_list).copied().collect()
         });
@@ -139,19 +119,22 @@ fn main() -> Result<()> {
     let non_allergenic_appearances: usize = not_possibly_allergenic
         .iter()
         .map(|ingredient_name| {
-            let count: usize = input
-                .split_whitespace()
-                .filter(|s| s == *ingredient_name)
+            let count: usize = foods
+                .iter()
+                .flat_map(|food| &food.ingredients)
+                .filter(|s| s == ingredient_name)
                 .count();
             count
         })
         .sum();
-    println!(
-        "total nonallergenic appearances: {}",
-        non_allergenic_appearances
-    );
+    //println!(
+    //"total nonallergenic appearances: {}",
+    //non_allergenic_appearances
+    //);
+    non_allergenic_appearances
+}
 
-    // part 2
+pub fn part2(foods: &[Food]) -> String {
     // approach:
     // - take original entries (ingredients -> allergens)
     // - find an entry with a single allergen (the ingredient must be in the list)
@@ -163,8 +146,8 @@ fn main() -> Result<()> {
     // - repeat
 
     let mut ingredient_listings = vec![];
-    for line in input.trim().lines() {
-        read_raw_to_ingredient_list(&mut ingredient_listings, &line);
+    for food in foods {
+        read_raw_to_ingredient_list(&mut ingredient_listings, food);
     }
     let mut final_ingredient_map = HashMap::new();
     let mut all_ingredient_listings = ingredient_listings.clone();
@@ -219,6 +202,26 @@ fn main() -> Result<()> {
         })
         .collect::<Vec<_>>()
         .join(",");
-    println!("ordered: {}", ordered_ingredients_str);
-    Ok(())
+    //println!("ordered: {}", ordered_ingredients_str);
+    ordered_ingredients_str
+}
+
+pub struct Day21 {}
+
+register_day!(2020, 21, Day21 {});
+
+impl Day for Day21 {
+    type Input<'a> = Vec<Food<'a>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(parse_foods(input)?)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
 }
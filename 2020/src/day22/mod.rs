@@ -1,11 +1,12 @@
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day, ParseError, Part};
 use eyre::Result;
 use std::collections::{HashSet, VecDeque};
-use std::fs::read_to_string;
 use std::{thread, time};
 
 const DEBUG: bool = false;
 
-enum CombatType {
+pub enum CombatType {
     Standard,
     Recursive,
 }
@@ -77,12 +78,12 @@ impl Combat {
     }
 
     fn print_game_state(&self, round_num: usize, card1: usize, card2: usize) {
-        println!();
-        println!("-- round {} game {} --", round_num, self.game_id);
-        println!("player 1 deck! {:?}", self.player1.deck);
-        println!("player 2 deck! {:?}", self.player2.deck);
-        println!("player 1 plays {:?}", card1);
-        println!("player 2 plays {:?}", card2);
+        eprintln!();
+        eprintln!("-- round {} game {} --", round_num, self.game_id);
+        eprintln!("player 1 deck! {:?}", self.player1.deck);
+        eprintln!("player 2 deck! {:?}", self.player2.deck);
+        eprintln!("player 1 plays {:?}", card1);
+        eprintln!("player 2 plays {:?}", card2);
     }
 
     fn play_recursive(&mut self) -> PlayerDeck {
@@ -100,7 +101,7 @@ impl Combat {
                 .contains(&(self.player1.clone(), self.player2.clone()))
             {
                 if DEBUG {
-                    println!("seen this state before! Player 1 wins!");
+                    eprintln!("seen this state before! Player 1 wins!");
                 }
                 return self.player1.clone();
             }
@@ -113,7 +114,7 @@ impl Combat {
             round_num += 1;
             if self.should_recurse(card1, card2) {
                 if DEBUG {
-                    println!("going to play a sub game");
+                    eprintln!("going to play a sub game");
                 }
                 // recursive
                 let recursive_player1 = PlayerDeck::new(
@@ -154,7 +155,7 @@ impl Combat {
             self.player1.deck.pop_front();
             self.player2.deck.pop_front();
             if DEBUG {
-                println!("round winner: {:?}", round_winner);
+                eprintln!("round winner: {:?}", round_winner);
             }
             match round_winner.name.as_str() {
                 "Player 1:" => {
@@ -170,12 +171,12 @@ impl Combat {
         }
         if self.player1.deck.is_empty() {
             if DEBUG {
-                println!("game winner! {:?}", self.player2);
+                eprintln!("game winner! {:?}", self.player2);
             }
             self.player2.clone()
         } else {
             if DEBUG {
-                println!("game winner! {:?}", self.player1);
+                eprintln!("game winner! {:?}", self.player1);
             }
             self.player1.clone()
         }
@@ -190,17 +191,25 @@ impl Combat {
 }
 
 #[derive(Debug, Hash, PartialEq, Eq, Clone)]
-struct PlayerDeck {
+pub struct PlayerDeck {
     name: String,
     deck: VecDeque<usize>,
 }
 
 impl PlayerDeck {
-    fn from_raw(raw_player_str: &str) -> Self {
+    /// Parse `name`'s deck from `raw_player_str`, a section of `input`
+    /// headed with the name.
+    pub fn from_raw(input: &str, raw_player_str: &str, name: &str) -> Result<Self, ParseError> {
         let mut lines = raw_player_str.lines();
-        let name = lines.next().unwrap();
-        let deck = lines.map(|s| s.parse::<usize>().unwrap()).collect();
-        Self::new(name, deck)
+        let header = lines.next().unwrap_or_default();
+        if header != name {
+            return Err(ParseError::at(input, header, format!("{:?}", name)));
+        }
+        let deck = lines
+            .filter(|s| !s.is_empty())
+            .map(|s| number(input, s))
+            .collect::<Result<_, _>>()?;
+        Ok(Self::new(name, deck))
     }
 
     fn new(name: &str, deck: VecDeque<usize>) -> Self {
@@ -211,38 +220,49 @@ impl PlayerDeck {
     }
 }
 
-fn main() -> Result<()> {
-    //let input = read_to_string("src/day22/input-sample2.txt")?;
-    let input = read_to_string("src/day22/input.txt")?;
-    let mut players = input.split("\n\n");
-    let player1 = PlayerDeck::from_raw(players.next().unwrap());
-    let player2 = PlayerDeck::from_raw(players.next().unwrap());
-    let mut game = Combat::new(1, CombatType::Standard, player1.clone(), player2.clone());
-
+pub fn score(game_type: CombatType, (player1, player2): &(PlayerDeck, PlayerDeck)) -> usize {
+    let mut game = Combat::new(1, game_type, player1.clone(), player2.clone());
     let winner = game.play();
-    println!("winner: {:?}", winner);
-    let score: usize = winner
+    //println!("winner: {:?}", winner);
+    winner
         .deck
         .iter()
         .rev()
         .enumerate()
         // add one for scoring
         .map(|(i, &n)| (i + 1) * n)
-        .sum();
-    println!("score: {:?}", score);
+        .sum()
+}
 
-    // part2
-    let mut game = Combat::new(1, CombatType::Recursive, player1, player2);
-    let winner = game.play();
-    println!("winner: {:?}", winner);
-    let score: usize = winner
-        .deck
-        .iter()
-        .rev()
-        .enumerate()
-        // add one for scoring
-        .map(|(i, &n)| (i + 1) * n)
-        .sum();
-    println!("score: {:?}", score);
-    Ok(())
+pub struct Day22 {}
+
+register_day!(2020, 22, Day22 {});
+
+impl Day for Day22 {
+    type Input<'a> = (PlayerDeck, PlayerDeck);
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        let (player1, player2) = input
+            .split_once("\n\n")
+            .ok_or_else(|| ParseError::end(input, "a second player"))?;
+        let player1 = PlayerDeck::from_raw(input, player1, "Player 1:")?;
+        let player2 = PlayerDeck::from_raw(input, player2, "Player 2:")?;
+        Ok((player1, player2))
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(score(CombatType::Standard, input).into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(score(CombatType::Recursive, input).into())
+    }
+
+    fn samples(&self, part: Part) -> &'static [u8] {
+        match part {
+            Part::One => &[1],
+            // the second sample loops forever without the recursive rules
+            Part::Two => &[1, 2],
+        }
+    }
 }
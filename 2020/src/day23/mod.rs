@@ -1,19 +1,20 @@
+use aoc_core::{register_day, Answer, Day, ParseError};
+use eyre::Result;
+use std::collections::HashSet;
 use std::{thread, time};
 
-//const INPUT: &str = "389125467"; // sample
-const INPUT: &str = "219347865"; // my input
 const DEBUG: bool = false;
 const MAGIC: usize = 5558672960;
 
 #[derive(Debug)]
-struct CupCircle {
+pub struct CupCircle {
     vals: Vec<usize>,
     current: usize,
     limits: (usize, usize),
 }
 
 impl CupCircle {
-    fn from_raw(initial: usize, raw_input: &str, len: usize) -> Self {
+    pub fn from_raw(initial: usize, raw_input: &str, len: usize) -> Self {
         let mut vals = vec![MAGIC; len + 1];
         let mut next_char_circle = raw_input.chars().cycle().skip(1);
         raw_input.chars().for_each(|ch| {
@@ -48,10 +49,10 @@ impl CupCircle {
     }
 
     fn next(&self, initial: usize) -> usize {
-        self.vals[initial] as usize
+        self.vals[initial]
     }
 
-    fn get_circle_repr(&self) -> Vec<usize> {
+    pub fn get_circle_repr(&self) -> Vec<usize> {
         let mut result = vec![];
         let mut ptr = self.current;
         loop {
@@ -64,7 +65,7 @@ impl CupCircle {
         result
     }
 
-    fn get_final_circle_repr(&self) -> String {
+    pub fn get_final_circle_repr(&self) -> String {
         let repr = self.get_circle_repr();
         let len = repr.len() - 1;
         repr.iter()
@@ -79,12 +80,12 @@ impl CupCircle {
     }
 
     fn print_round_state(&self, round_num: usize, destination: usize, next_three: &[usize; 3]) {
-        println!();
-        println!("-- round {} --", round_num);
-        println!("circle: {:?}", self.get_circle_repr());
-        println!("current: {}", self.current);
-        println!("next three: {:?}", next_three);
-        println!("destination: {}", destination);
+        eprintln!();
+        eprintln!("-- round {} --", round_num);
+        eprintln!("circle: {:?}", self.get_circle_repr());
+        eprintln!("current: {}", self.current);
+        eprintln!("next three: {:?}", next_three);
+        eprintln!("destination: {}", destination);
     }
 
     fn find_destination(&self, next_three: &[usize; 3]) -> usize {
@@ -99,7 +100,7 @@ impl CupCircle {
                 break;
             } else {
                 if DEBUG {
-                    println!(
+                    eprintln!(
                         "{} not in circle or in next three, skipping..",
                         target_destination
                     );
@@ -110,12 +111,12 @@ impl CupCircle {
         target_destination
     }
 
-    fn play(&mut self, rounds: usize) {
+    pub fn play(&mut self, rounds: usize) {
         let mut next_three = [0; 3]; // next three indices
         let mut destination: usize; // destination index
         for round_num in 0..rounds {
             if DEBUG && round_num % 100_000 == 0 {
-                println!("{}", round_num);
+                eprintln!("{}", round_num);
             }
             next_three[0] = self.next(self.current);
             next_three[1] = self.next(next_three[0]);
@@ -139,30 +140,59 @@ impl CupCircle {
     }
 }
 
-fn main() {
-    let mut circle = CupCircle::from_raw(
-        INPUT.chars().next().unwrap().to_digit(10).unwrap() as usize,
-        INPUT,
-        INPUT.len(),
-    );
-    circle.play(100);
-    let final_circle = circle.get_final_circle_repr();
-    println!("{:?}", final_circle);
-    assert_eq!(final_circle, "36472598");
-
-    // part2
-    let mut circle = CupCircle::from_raw(
-        INPUT.chars().next().unwrap().to_digit(10).unwrap() as usize,
-        INPUT,
-        1_000_000,
-    );
-    circle.play(10_000_000);
-    let final_circle = circle.get_circle_repr();
-    let final_product: usize = final_circle
-        .iter()
-        .skip_while(|x| **x != 1)
-        .skip(1)
-        .take(2)
-        .product();
-    println!("final product: {:?}", final_product);
+pub struct Day23 {}
+
+register_day!(2020, 23, Day23 {});
+
+impl Day for Day23 {
+    type Input<'a> = &'a str;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        let cups = input.trim();
+        if cups.is_empty() {
+            return Err(ParseError::end(input, "cup labels").into());
+        }
+        // the circle needs every label from 1 up to the number of cups
+        let mut seen = HashSet::new();
+        for (i, ch) in cups.char_indices() {
+            let label = &cups[i..i + ch.len_utf8()];
+            match ch.to_digit(10) {
+                Some(n) if n >= 1 && n as usize <= cups.len() && seen.insert(n) => {}
+                _ => {
+                    let expected = format!("each label from 1 to {} once", cups.len());
+                    return Err(ParseError::at(input, label, expected).into());
+                }
+            }
+        }
+        Ok(cups)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        let mut circle = CupCircle::from_raw(
+            input.chars().next().unwrap().to_digit(10).unwrap() as usize,
+            input,
+            input.len(),
+        );
+        circle.play(100);
+        let final_circle = circle.get_final_circle_repr();
+        //println!("{:?}", final_circle);
+        Ok(final_circle.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        let mut circle = CupCircle::from_raw(
+            input.chars().next().unwrap().to_digit(10).unwrap() as usize,
+            input,
+            1_000_000,
+        );
+        circle.play(10_000_000);
+        let final_circle = circle.get_circle_repr();
+        let final_product: usize = final_circle
+            .iter()
+            .skip_while(|x| **x != 1)
+            .skip(1)
+            .take(2)
+            .product();
+        Ok(final_product.into())
+    }
 }
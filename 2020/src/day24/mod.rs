@@ -0,0 +1,77 @@
+use aoc_core::automaton::{Automaton, Hex, LifeRule, Neighborhood};
+use aoc_core::day::no_such_option;
+use aoc_core::hex::{HexCoord, HexDirection};
+use aoc_core::{register_day, Answer, Day};
+use eyre::Result;
+use std::collections::HashSet;
+
+/// Black tiles with no black neighbors or more than 2 flip to white, and
+/// white tiles with exactly 2 black neighbors flip to black.
+pub const RULE: &str = "B2/S12H";
+
+/// The tiles left black after flipping the tile at the end of each path,
+/// starting from all white.
+pub fn flip_tiles(paths: &[Vec<HexDirection>]) -> HashSet<HexCoord> {
+    let mut black = HashSet::new();
+    for path in paths {
+        let tile = HexCoord::ORIGIN.walk(path);
+        //println!("flipping {}", tile);
+        if !black.remove(&tile) {
+            black.insert(tile);
+        }
+    }
+    black
+}
+
+/// Let the floor live by `rule` for `days` days, returning how many tiles
+/// are black at the end.
+pub fn live(black: &HashSet<HexCoord>, rule: &LifeRule, days: usize) -> Result<usize> {
+    rule.check_neighborhood(&[Neighborhood::Hex])?;
+    rule.check_unbounded()?;
+    let mut floor = Automaton::new(Hex, rule.clone(), black.iter().copied());
+    floor.run(days);
+    Ok(floor.count_alive())
+}
+
+pub struct Day24 {
+    rule: LifeRule,
+}
+
+impl Default for Day24 {
+    fn default() -> Self {
+        Day24 {
+            rule: RULE.parse().expect("the puzzle's rule parses"),
+        }
+    }
+}
+
+register_day!(2020, 24, Day24::default());
+
+impl Day for Day24 {
+    type Input<'a> = Vec<Vec<HexDirection>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(input
+            .lines()
+            .map(|line| HexDirection::parse_path(input, line))
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(flip_tiles(input).len().into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        let black = flip_tiles(input);
+        //println!("day 0 black count: {}", black.len());
+        Ok(live(&black, &self.rule, 100)?.into())
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "rule" => self.rule = value.parse()?,
+            _ => return Err(no_such_option(key)),
+        }
+        Ok(())
+    }
+}
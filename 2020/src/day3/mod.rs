@@ -0,0 +1,99 @@
+use aoc_core::grid::{FromChar, Grid};
+use aoc_core::{register_day, Answer, Day, ParseError};
+use eyre::Result;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Square {
+    Open,
+    Tree,
+}
+
+impl FromChar for Square {
+    const EXPECTED: &'static str = "'.' or '#'";
+
+    fn from_char(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Square::Open),
+            '#' => Some(Square::Tree),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Map {
+    locations: Grid<Square>,
+    toboggan: Toboggan,
+    trees_found: u32,
+}
+
+#[derive(Clone)]
+struct Toboggan {
+    row: usize,
+    col: usize,
+}
+
+impl Map {
+    fn new(locations: Grid<Square>) -> Map {
+        //println!("new map with width:{}, height:{}", locations.width(), locations.height());
+        Map {
+            locations,
+            toboggan: Toboggan { row: 0, col: 0 },
+            trees_found: 0,
+        }
+    }
+
+    fn slide(&mut self, hz: usize, vrt: usize) {
+        self.toboggan.row += vrt;
+        self.toboggan.col += hz;
+        self.toboggan.col %= self.locations.width();
+
+        if self.toboggan.row > self.locations.height() - 1 {
+            return;
+        }
+        if self.locations[(self.toboggan.col, self.toboggan.row)] == Square::Tree {
+            //println!("found tree! {},{}", self.toboggan.row, self.toboggan.col);
+            self.trees_found += 1;
+        }
+    }
+}
+
+pub fn gen_map(map_str: &str) -> Result<Map, ParseError> {
+    Ok(Map::new(Grid::parse(map_str)?))
+}
+
+pub fn trees_hit(mut map: Map, horizontal_slide: usize, vertical_slide: usize) -> u32 {
+    while map.toboggan.row < map.locations.height() - 1 {
+        map.slide(horizontal_slide, vertical_slide);
+    }
+    //println!(
+    //"found {} trees for {}, {}",
+    //map.trees_found, horizontal_slide, vertical_slide
+    //);
+    map.trees_found
+}
+
+pub struct Day3 {}
+
+register_day!(2020, 3, Day3 {});
+
+impl Day for Day3 {
+    type Input<'a> = Map;
+
+    fn parse<'a>(&self, map_str: &'a str) -> Result<Self::Input<'a>> {
+        Ok(gen_map(map_str)?)
+    }
+
+    fn part_1(&self, map: &Self::Input<'_>) -> Result<Answer> {
+        Ok(trees_hit(map.clone(), 3, 1).into())
+    }
+
+    fn part_2(&self, map: &Self::Input<'_>) -> Result<Answer> {
+        let t11 = trees_hit(map.clone(), 1, 1);
+        let t31 = trees_hit(map.clone(), 3, 1);
+        let t51 = trees_hit(map.clone(), 5, 1);
+        let t71 = trees_hit(map.clone(), 7, 1);
+        let t12 = trees_hit(map.clone(), 1, 2);
+        Ok((t11 * t31 * t51 * t71 * t12).into())
+    }
+}
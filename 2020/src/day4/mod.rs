@@ -0,0 +1,156 @@
+use aoc_core::{register_day, Answer, Day, ParseError};
+use eyre::Result;
+use regex::Regex;
+use std::collections::HashMap;
+use std::vec::Vec;
+
+/// A passport's fields, by key.
+pub type Passport<'a> = HashMap<&'a str, &'a str>;
+
+pub fn parse_passports(input: &str) -> Result<Vec<Passport<'_>>, ParseError> {
+    let mut passports = vec![];
+    for line in input.split("\n\n") {
+        let mut passport_map = HashMap::new();
+        for l in line.split([' ', '\n']) {
+            if l.is_empty() {
+                continue;
+            }
+            let (k, v) = l
+                .split_once(':')
+                .ok_or_else(|| ParseError::at(input, l, "a key:value field"))?;
+            passport_map.insert(k, v);
+        }
+        if !passport_map.is_empty() {
+            passports.push(passport_map);
+        }
+    }
+    Ok(passports)
+}
+
+pub fn part1(passports: &[Passport]) -> usize {
+    let mut valid_fields = vec!["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+    valid_fields.sort_unstable();
+    let mut valid_count = 0;
+
+    for passport in passports {
+        let mut passport_map = passport.clone();
+        passport_map.remove("cid");
+        let mut keys_found_str: Vec<&str> = passport_map.keys().copied().collect();
+        keys_found_str.sort_unstable();
+        let matching = keys_found_str
+            .iter()
+            .zip(&valid_fields)
+            // .map(|(a, b)| {
+            // println!("{:?}, {:?}. Equal? {}", a, b, a == b);
+            // (a, b)
+            // })
+            .filter(|&(a, b)| a == b)
+            .count();
+        let valid_passport = matching == valid_fields.len();
+        if valid_passport {
+            valid_count += 1;
+        }
+    }
+    valid_count
+}
+
+fn in_range(v: &str, range: std::ops::RangeInclusive<i32>) -> bool {
+    v.parse::<i32>().is_ok_and(|val| range.contains(&val))
+}
+
+fn validate(k: &str, v: &str) -> bool {
+    match k {
+        "byr" => v.len() == 4 && in_range(v, 1920..=2002),
+        "iyr" => v.len() == 4 && in_range(v, 2010..=2020),
+        "eyr" => v.len() == 4 && in_range(v, 2020..=2030),
+        "hgt" => {
+            let is_cm = v.contains("cm");
+            let is_in = v.contains("in");
+            if is_cm && is_in {
+                return false;
+            }
+            if is_cm {
+                let res: Vec<&str> = v.split("cm").collect();
+                // println!("{} cm ", res[0]);
+                in_range(res[0], 150..=192)
+            } else if is_in {
+                let res: Vec<&str> = v.split("in").collect();
+                // println!("{} in ", res[0]);
+                in_range(res[0], 59..=76)
+            } else {
+                false
+            }
+        }
+        "hcl" => {
+            let re = Regex::new(r"\#[a-f0-9]{6}").unwrap();
+            re.is_match(v)
+        }
+        "ecl" => ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"]
+            .iter()
+            .find(|s| s == &&v)
+            .is_some(),
+        "pid" => {
+            let re = Regex::new(r"^[0-9]{9}$").unwrap();
+            re.is_match(v)
+        }
+        "cid" => true,
+        _ => {
+            eprintln!("unknown value {}", k);
+            false
+        }
+    }
+}
+
+pub fn part2(passports: &[Passport]) -> usize {
+    let mut valid_fields = vec!["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+    valid_fields.sort_unstable();
+    let mut valid_count = 0;
+
+    let mut invalid_count_map = HashMap::new();
+    for passport in passports {
+        let mut passport_map = HashMap::new();
+        for (&k, &v) in passport {
+            if validate(k, v) {
+                passport_map.insert(k, v);
+            } else {
+                //println!("invalid {}", k);
+                let counter = invalid_count_map.entry(k).or_insert(0);
+                *counter += 1;
+            }
+        }
+        passport_map.remove("cid");
+        let mut keys_found_str: Vec<&str> = passport_map.keys().copied().collect();
+        keys_found_str.sort_unstable();
+        let matching = keys_found_str
+            .iter()
+            .zip(&valid_fields)
+            .filter(|&(a, b)| a == b)
+            .count();
+        let valid_passport = matching == valid_fields.len();
+        if valid_passport {
+            valid_count += 1;
+        }
+    }
+    //println!("{:?}", invalid_count_map);
+    valid_count
+}
+
+pub struct Day4 {}
+
+register_day!(2020, 4, Day4 {});
+
+impl Day for Day4 {
+    type Input<'a> = Vec<Passport<'a>>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(parse_passports(input)?)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
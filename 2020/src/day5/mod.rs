@@ -0,0 +1,89 @@
+use aoc_core::{register_day, Answer, Day, ParseError, Part};
+use eyre::{eyre, Result};
+use std::cmp::max;
+use std::vec::Vec;
+
+/// The seat ID of each boarding pass, which is its row and column read as
+/// one binary number.
+pub fn parse_seats(input: &str) -> Result<Vec<usize>, ParseError> {
+    let mut seats = vec![];
+    for line in input.split_whitespace() {
+        if line.len() != 10 {
+            return Err(ParseError::at(
+                input,
+                line,
+                "a boarding pass like FBFBBFFRLR",
+            ));
+        }
+        let mut seat_id = 0;
+        for (i, c) in line.char_indices() {
+            let bit = match (i < 7, c) {
+                (true, 'F') | (false, 'L') => 0,
+                (true, 'B') | (false, 'R') => 1,
+                (true, _) => {
+                    return Err(ParseError::at(input, &line[i..i + c.len_utf8()], "F or B"))
+                }
+                (false, _) => {
+                    return Err(ParseError::at(input, &line[i..i + c.len_utf8()], "L or R"))
+                }
+            };
+            seat_id = seat_id * 2 + bit;
+        }
+        seats.push(seat_id);
+    }
+    Ok(seats)
+}
+
+pub fn part1(seats: &[usize]) -> usize {
+    let mut max_seat_id = 0;
+    for &seat_id in seats {
+        max_seat_id = max(seat_id, max_seat_id);
+    }
+
+    //println!("max seat id was {}", max_seat_id);
+    max_seat_id
+}
+
+pub fn part2(seats: &[usize]) -> Option<usize> {
+    // every seat ID fits in ten bits
+    let mut seats_full = vec![0; 1 << 10];
+    for &seat_id in seats {
+        seats_full[seat_id] = 1;
+    }
+    //println!("seats full {:?}", seats_full);
+    let index = seats_full
+        .iter()
+        .enumerate()
+        .skip_while(|(_, v)| **v == 0)
+        .skip_while(|(_, v)| **v == 1)
+        .take(1)
+        .next();
+    //println!("found my seat! seat id is {:?}", index.unwrap().0);
+    index.map(|(i, _)| i)
+}
+
+pub struct Day5 {}
+
+register_day!(2020, 5, Day5 {});
+
+impl Day for Day5 {
+    type Input<'a> = Vec<usize>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(parse_seats(input)?)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        let seat = part2(input).ok_or_else(|| eyre!("no empty seat between full ones"))?;
+        Ok(seat.into())
+    }
+
+    fn samples(&self, _part: Part) -> &'static [u8] {
+        // the puzzle only gives single boarding passes as examples
+        &[]
+    }
+}
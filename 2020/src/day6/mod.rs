@@ -0,0 +1,77 @@
+use aoc_core::{register_day, Answer, Day, ParseError};
+use eyre::Result;
+use std::collections::HashSet;
+
+/// Each group's people, as the questions each of them answered yes to.
+pub type Group = Vec<HashSet<char>>;
+
+pub fn parse_groups(input_str: &str) -> Result<Vec<Group>, ParseError> {
+    let mut groups = vec![];
+    for entry in input_str.split("\n\n") {
+        let mut group = vec![];
+        for person_answer in entry.lines() {
+            let mut questions = HashSet::new();
+            for (i, c) in person_answer.char_indices() {
+                if !c.is_ascii_lowercase() {
+                    let question = &person_answer[i..i + c.len_utf8()];
+                    return Err(ParseError::at(
+                        input_str,
+                        question,
+                        "a question from a to z",
+                    ));
+                }
+                questions.insert(c);
+            }
+            group.push(questions);
+        }
+        if !group.is_empty() {
+            groups.push(group);
+        }
+    }
+    Ok(groups)
+}
+
+pub fn part1(groups: &[Group]) -> usize {
+    let mut count = 0;
+    for group in groups {
+        let questions: HashSet<char> = group.iter().flatten().copied().collect();
+        count += questions.len();
+    }
+    //println!("total of {}", count);
+    count
+}
+
+pub fn part2(groups: &[Group]) -> usize {
+    let mut count = 0;
+    for group in groups {
+        let mut answered_by_all: HashSet<char> = ('a'..='z').collect();
+        for set in group {
+            //println!("{:?}", set);
+            answered_by_all = answered_by_all.intersection(set).copied().collect();
+        }
+        count += answered_by_all.len();
+        //println!("final: {:?}", answered_by_all.len());
+    }
+    //println!("total of {}", count);
+    count
+}
+
+pub struct Day6 {}
+
+register_day!(2020, 6, Day6 {});
+
+impl Day for Day6 {
+    type Input<'a> = Vec<Group>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(parse_groups(input)?)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part1(input).into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(part2(input).into())
+    }
+}
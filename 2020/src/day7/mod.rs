@@ -0,0 +1,151 @@
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day, ParseError, Symbol};
+use eyre::Result;
+use regex::Regex;
+use std::collections::HashMap;
+use std::fmt;
+use std::vec::Vec;
+
+#[derive(Debug)]
+struct BagNode {
+    color: Symbol,
+    contents: HashMap<Symbol, u32>,
+}
+
+#[derive(Debug)]
+pub struct BagGraph {
+    nodes: HashMap<Symbol, BagNode>,
+}
+
+impl fmt::Display for BagNode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "(color:\n{:?},\n contents:\n{:?}\n)",
+            self.color, self.contents
+        )
+    }
+}
+
+impl BagNode {
+    fn new(color: Symbol) -> BagNode {
+        BagNode {
+            color,
+            contents: HashMap::new(),
+        }
+    }
+
+    fn add_contents(&mut self, n: Symbol, count: u32) {
+        self.contents.insert(n, count);
+    }
+}
+
+impl BagGraph {
+    fn new() -> BagGraph {
+        BagGraph {
+            nodes: HashMap::new(),
+        }
+    }
+
+    fn add_node(&mut self, color: Symbol, contents: Vec<(Option<Symbol>, u32)>) {
+        let new_node = self
+            .nodes
+            .entry(color)
+            .or_insert_with(|| BagNode::new(color));
+        for n in contents {
+            if let Some(content) = n.0 {
+                new_node.add_contents(content, n.1);
+            }
+        }
+    }
+
+    fn node_can_contain(&self, node_color: Symbol, search_color: Symbol) -> bool {
+        let parent_node = match self.nodes.get(&node_color) {
+            Some(node) => node,
+            // a bag with no rule of its own holds nothing
+            None => return false,
+        };
+        // if the search color is in the immediate children we are good.
+        if parent_node.contents.contains_key(&search_color) {
+            return true;
+        }
+
+        // recurse on each of the children, searching for the search color
+        for &contents_color in parent_node.contents.keys() {
+            if self.node_can_contain(contents_color, search_color) {
+                return true;
+            }
+        }
+        // no node in this path has the search color
+        false
+    }
+
+    pub fn count_containers_of(&self, color: Symbol) -> usize {
+        let mut count = 0;
+        for node in self.nodes.values() {
+            if self.node_can_contain(node.color, color) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    pub fn count_contents(&self, color: Symbol) -> u32 {
+        let mut count = 1;
+        let current_root = match self.nodes.get(&color) {
+            Some(node) => node,
+            None => return count,
+        };
+        for (&content, content_count) in &current_root.contents {
+            count += content_count * self.count_contents(content);
+        }
+        count
+    }
+}
+
+pub fn gen_graph(input: &str) -> Result<BagGraph, ParseError> {
+    let main_re = Regex::new(r"^(.*) bags contain (.*)\.$").unwrap();
+    let contains_re = Regex::new(r"^(\d+) (.*) bags?$").unwrap();
+    let mut graph = BagGraph::new();
+
+    for line in input.lines().filter(|line| !line.is_empty()) {
+        let rule = main_re.captures(line).ok_or_else(|| {
+            ParseError::at(input, line, "a rule like \"<color> bags contain ...\"")
+        })?;
+        let primary_color = Symbol::new(&rule[1]);
+        //println!("color: {:?}", &rule[1]);
+        let mut contains_results: Vec<(Option<Symbol>, u32)> = vec![];
+        if &rule[2] != "no other bags" {
+            for contains in rule[2].split(", ") {
+                let contained = contains_re
+                    .captures(contains)
+                    .ok_or_else(|| ParseError::at(input, contains, "a count and a bag color"))?;
+                let child_color = Symbol::new(&contained[2]);
+                contains_results.push((Some(child_color), number(input, &contained[1])?));
+            }
+        }
+        graph.add_node(primary_color, contains_results);
+    }
+    Ok(graph)
+}
+
+pub struct Day7 {}
+
+register_day!(2020, 7, Day7 {});
+
+impl Day for Day7 {
+    type Input<'a> = BagGraph;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(gen_graph(input)?)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(input.count_containers_of(Symbol::new("shiny gold")).into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        // subtract one because the shiny gold bag itself doesn't count
+        Ok((input.count_contents(Symbol::new("shiny gold")) - 1).into())
+    }
+}
@@ -1,8 +1,10 @@
-use eyre::Result;
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day, ParseError};
+use eyre::{eyre, Result};
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::HashSet;
-use std::fs::read_to_string;
+use std::str::FromStr;
 use std::vec::Vec;
 
 lazy_static! {
@@ -10,38 +12,45 @@ lazy_static! {
 }
 
 #[derive(Debug)]
-enum RunStatus {
+pub enum RunStatus {
     Running,
     Loop,
     EndOfProgram,
 }
 
-#[derive(Debug)]
-struct Program {
+#[derive(Debug, Clone)]
+pub struct Program {
     instruction_pointer: i32,
     code: Vec<(String, i32)>,
     visited: HashSet<i32>,
-    accumulator: i32,
+    pub accumulator: i32,
 }
 
-impl Program {
-    fn from_file(filename: &str) -> Result<Program> {
-        let input = read_to_string(filename)?;
+impl FromStr for Program {
+    type Err = ParseError;
 
+    fn from_str(input: &str) -> Result<Program, ParseError> {
         let program_code: Vec<(String, i32)> = input
             .split('\n')
             .filter(|line| !line.is_empty())
             .map(|line| {
-                let cap = INSTRUCTION_RE.captures_iter(line).next().unwrap();
+                let cap = INSTRUCTION_RE.captures(line).ok_or_else(|| {
+                    ParseError::at(input, line, "an operation and a signed count")
+                })?;
+                if !["acc", "jmp", "nop"].contains(&&cap[1]) {
+                    return Err(ParseError::at(input, &cap[1], "acc, jmp or nop"));
+                }
                 let op = cap[1].to_string();
-                let count = cap[2].parse::<i32>().unwrap();
+                let count = number(input, &cap[2])?;
                 //println!("op: {}, count: {}", op, count);
-                (op, count)
+                Ok((op, count))
             })
-            .collect();
+            .collect::<Result<_, _>>()?;
         Ok(Program::new(program_code))
     }
+}
 
+impl Program {
     fn new(code: Vec<(String, i32)>) -> Program {
         Program {
             instruction_pointer: 0,
@@ -72,7 +81,7 @@ impl Program {
         RunStatus::Running
     }
 
-    fn run(&mut self) -> RunStatus {
+    pub fn run(&mut self) -> RunStatus {
         let result: RunStatus;
         // run program operations until the end of the program is reached.
         loop {
@@ -122,11 +131,11 @@ impl Program {
         result
     }
 
-    fn fix_and_run(&self) -> Option<Program> {
+    pub fn fix_and_run(&self) -> Option<Program> {
         for possible_variation in self.get_possible_corrupt_variations() {
             let mut possibly_fixed_program = Program::new(possible_variation);
             if let RunStatus::EndOfProgram = possibly_fixed_program.run() {
-                println!("found the end!");
+                //println!("found the end!");
                 return Some(possibly_fixed_program);
             }
         }
@@ -134,18 +143,27 @@ impl Program {
     }
 }
 
-fn main() -> Result<()> {
-    //let mut program = Program::from_file("src/day8/input-sample.txt")?;
-    let mut program = Program::from_file("src/day8/input.txt")?;
-    let result = program.run();
-    //println!("final program result: {:?}", program);
-    println!("final program accumulator value: {}", program.accumulator);
-    println!("final result status: {:?}", result);
+pub struct Day8 {}
+
+register_day!(2020, 8, Day8 {});
 
-    println!("part 2");
-    let program = Program::from_file("src/day8/input.txt")?;
-    let result = program.fix_and_run().unwrap();
-    println!("final program accumulator value: {}", result.accumulator);
+impl Day for Day8 {
+    type Input<'a> = Program;
 
-    Ok(())
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(input.parse()?)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        let mut program = input.clone();
+        program.run();
+        Ok(program.accumulator.into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        let result = input
+            .fix_and_run()
+            .ok_or_else(|| eyre!("no fix ends the program"))?;
+        Ok(result.accumulator.into())
+    }
 }
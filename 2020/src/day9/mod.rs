@@ -0,0 +1,101 @@
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day, ParseError, Part};
+use eyre::{eyre, Result};
+
+pub const PREAMBLE_SIZE: usize = 25;
+
+fn find_preceding_sum(v: Vec<&u64>, n: &u64) -> Option<(usize, usize)> {
+    //println!("searching for {}", n);
+    for (i, num1) in v.iter().enumerate() {
+        for (j, num2) in v.iter().skip(i).enumerate() {
+            let result = **num1 + **num2;
+            //println!("({},{}): {} + {} = {}", i, i + 1 + j, num1, num2, result);
+            if result == *n {
+                //println!("found it!");
+                return Some((i, j));
+            }
+        }
+    }
+    //println!("Searching for {} failed on {:?}", n, v);
+    None
+}
+
+pub fn find_contiguous_sum(v: Vec<u64>, n: &u64) -> Option<u64> {
+    for i in 0..v.len() {
+        for j in i + 1..v.len() + 1 {
+            //println!("{},{}", i, j);
+            let range_iter = v.iter().take(j).skip(i).copied();
+            let sum = range_iter.clone().sum::<u64>();
+            if sum == *n {
+                //println!("found it!");
+                let min = range_iter.clone().min().unwrap();
+                let max = range_iter.clone().max().unwrap();
+                return Some(min + max);
+            }
+        }
+    }
+    None
+}
+
+pub fn first_invalid(nums: &[u64]) -> u64 {
+    let last_possible_preamble = nums.len() - PREAMBLE_SIZE - 1;
+
+    let mut preceding_sum_failure: u64 = 0;
+    for preamble_index in 0..last_possible_preamble {
+        let search_num = &nums[PREAMBLE_SIZE + preamble_index];
+        match find_preceding_sum(
+            nums.iter()
+                .skip(preamble_index)
+                .take(PREAMBLE_SIZE)
+                .collect(),
+            search_num,
+        ) {
+            Some(_result) => {}
+            None => {
+                //println!(
+                //"found first non matching number at index {}: {}",
+                //PREAMBLE_SIZE + preamble_index,
+                //search_num
+                //);
+                preceding_sum_failure = *search_num;
+            }
+        }
+    }
+    preceding_sum_failure
+}
+
+pub struct Day9 {}
+
+register_day!(2020, 9, Day9 {});
+
+impl Day for Day9 {
+    type Input<'a> = Vec<u64>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        let nums: Vec<u64> = input
+            .split_whitespace()
+            .filter(|s| !s.is_empty())
+            .map(|s| number(input, s))
+            .collect::<Result<_, _>>()?;
+        if nums.len() <= PREAMBLE_SIZE {
+            let expected = format!("more numbers than the {} in the preamble", PREAMBLE_SIZE);
+            return Err(ParseError::end(input, expected).into());
+        }
+        Ok(nums)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        Ok(first_invalid(input).into())
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        let sum = find_contiguous_sum(input.clone(), &first_invalid(input))
+            .ok_or_else(|| eyre!("no contiguous range sums to the invalid number"))?;
+        Ok(sum.into())
+    }
+
+    fn samples(&self, _part: Part) -> &'static [u8] {
+        // the example uses a shorter preamble than PREAMBLE_SIZE
+        &[]
+    }
+}
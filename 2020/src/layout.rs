@@ -0,0 +1,21 @@
+//! Where this year's inputs and solutions live. Shared with the build
+//! script, which generates a test for each sample from it.
+
+use aoc_core::{Layout, Source};
+
+/// Each day keeps its inputs alongside its code, as `dayN/input.txt` with
+/// samples as `dayN/input-sample.txt`, `dayN/input-sample2.txt` and so on.
+pub const LAYOUT: Layout = Layout {
+    year: 2020,
+    dir: concat!(env!("CARGO_MANIFEST_DIR"), "/src"),
+    input: |day| format!("day{}/input.txt", day),
+    sample: |day, n| match n {
+        1 => format!("day{}/input-sample.txt", day),
+        _ => format!("day{}/input-sample{}.txt", day, n),
+    },
+    source: Source {
+        dir: concat!(env!("CARGO_MANIFEST_DIR"), "/src"),
+        module: |day| format!("day{}/mod.rs", day),
+        declarations: "lib.rs",
+    },
+};
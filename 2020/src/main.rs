@@ -0,0 +1,8 @@
+// the days register themselves when the library is linked in
+extern crate aoc_2020;
+
+use eyre::Result;
+
+fn main() -> Result<()> {
+    aoc_core::cli::main(&aoc_2020::LAYOUT)
+}
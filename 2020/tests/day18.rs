@@ -0,0 +1,38 @@
+use aoc_2020::day18::{evaluate, Precedence};
+
+#[test]
+fn evaluates_left_to_right() {
+    assert_eq!(
+        evaluate("1 + 2 * 3 + 4 * 5 + 6", Precedence::Equal).unwrap(),
+        71
+    );
+    assert_eq!(evaluate("2 * 3 + (4 * 5)", Precedence::Equal).unwrap(), 26);
+    assert_eq!(
+        evaluate(
+            "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2",
+            Precedence::Equal
+        )
+        .unwrap(),
+        13632
+    );
+}
+
+#[test]
+fn evaluates_addition_first() {
+    assert_eq!(
+        evaluate("1 + 2 * 3 + 4 * 5 + 6", Precedence::AddFirst).unwrap(),
+        231
+    );
+    assert_eq!(
+        evaluate("2 * 3 + (4 * 5)", Precedence::AddFirst).unwrap(),
+        46
+    );
+    assert_eq!(
+        evaluate(
+            "((2 + 4 * 9) * (6 + 9 * 8 + 6) + 6) + 2 + 4 * 2",
+            Precedence::AddFirst
+        )
+        .unwrap(),
+        23340
+    );
+}
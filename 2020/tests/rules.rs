@@ -0,0 +1,46 @@
+use aoc_2020::{day11, day17, day24};
+use aoc_core::automaton::LifeRule;
+use aoc_core::grid::Grid;
+use aoc_core::Day;
+
+fn rule(rule: &str) -> LifeRule {
+    rule.parse().unwrap()
+}
+
+#[test]
+fn conway_cubes() {
+    let slice = Grid::parse(include_str!("../src/day17/input-sample.txt")).unwrap();
+    assert_eq!(day17::boot::<3>(&slice, &rule("B3/S23"), 6).unwrap(), 112);
+    assert_eq!(day17::boot::<4>(&slice, &rule("B3/S23"), 6).unwrap(), 848);
+    assert!(day17::boot::<3>(&slice, &rule("B0/S23"), 6).is_err());
+}
+
+#[test]
+fn seating_system() {
+    let seats = Grid::parse(include_str!("../src/day11/input-sample.txt")).unwrap();
+    assert_eq!(day11::settle(&seats, &rule("B0/S0123")).unwrap(), 37);
+    assert_eq!(day11::settle(&seats, &rule("B0/S<5L")).unwrap(), 26);
+    assert!(day11::settle(&seats, &rule("B0/S0123H")).is_err());
+}
+
+#[test]
+fn seating_that_never_settles() {
+    let blinker = Grid::parse("LLL\n###\nLLL\n").unwrap();
+    let error = day11::settle(&blinker, &rule("B3/S23")).unwrap_err();
+    assert!(
+        error.to_string().contains("cycles with period 2"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn lobby_layout() {
+    let day = day24::Day24::default();
+    let paths = day
+        .parse(include_str!("../src/day24/input-sample.txt"))
+        .unwrap();
+    let black = day24::flip_tiles(&paths);
+    assert_eq!(day24::live(&black, &rule("B2/S12H"), 100).unwrap(), 2208);
+    assert!(day24::live(&black, &rule("B2/S12"), 100).is_err());
+}
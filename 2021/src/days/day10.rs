@@ -1,10 +1,10 @@
-use super::Day;
-use eyre::Result;
+use aoc_core::{register_day, Answer, Day, ParseError};
+use eyre::{eyre, Result, WrapErr};
 use lazy_static::lazy_static;
+use log::debug;
 use maplit::hashmap;
 use std::collections::HashMap;
 use std::fmt;
-use std::fs::read_to_string;
 
 const OPEN: &str = "({[<";
 lazy_static! {
@@ -35,26 +35,26 @@ impl fmt::Display for MatchError {
     }
 }
 
+#[derive(Default)]
 pub struct Day10 {}
 
+register_day!(2021, 10, Day10::new());
+
 impl Day10 {
     pub fn new() -> Self {
         Self {}
     }
 
-    fn parse_input(&mut self, raw_input: &str) -> Result<Vec<Vec<char>>> {
-        Ok(raw_input
-            .lines()
-            .map(|l| l.chars().collect::<Vec<_>>())
-            .collect::<Vec<_>>())
-    }
-
-    fn process_line(&self, stack: &mut Vec<char>, line: &[char]) -> Result<(), MatchError> {
+    /// The outer error is for a line that can't be read at all, the inner
+    /// one for a corrupt line.
+    fn process_line(&self, stack: &mut Vec<char>, line: &[char]) -> Result<Result<(), MatchError>> {
         for &ch in line {
             if OPEN.contains(ch) {
                 stack.push(ch);
             } else {
-                let matchable = stack.pop().unwrap();
+                let matchable = stack
+                    .pop()
+                    .ok_or_else(|| eyre!("{:?} closes a chunk that was never opened", ch))?;
                 //println!("trying for {:?}{:?}", matchable, ch);
                 if CLOSE_MAP[&matchable] != ch {
                     // corrupt line
@@ -62,13 +62,13 @@ impl Day10 {
                     //    "corrupt on char {:?} ({:?}) doesn't match {:?}",
                     //    matchable, CLOSE_MAP[&matchable], ch
                     //);
-                    return Err(MatchError { culprit: ch });
+                    return Ok(Err(MatchError { culprit: ch }));
                 } else {
                     //println!("matched {}", ch);
                 }
             }
         }
-        Ok(())
+        Ok(Ok(()))
     }
 
     fn score_completion(&self, unmatched_chars: &[char]) -> usize {
@@ -79,13 +79,33 @@ impl Day10 {
         }
         score
     }
+}
+
+impl Day for Day10 {
+    type Input<'a> = Vec<Vec<char>>;
+
+    fn parse<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>> {
+        raw_input
+            .lines()
+            .map(
+                |l| match l.char_indices().find(|(_, ch)| !"()[]{}<>".contains(*ch)) {
+                    Some((i, ch)) => {
+                        Err(ParseError::at(raw_input, &l[i..i + ch.len_utf8()], "a bracket").into())
+                    }
+                    None => Ok(l.chars().collect::<Vec<_>>()),
+                },
+            )
+            .collect()
+    }
 
-    fn part_1(&mut self, raw_input: &str) -> Result<()> {
-        let input = self.parse_input(raw_input)?;
-        let mut stack = vec![];
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
         let mut error_score = 0;
-        for (_i, line) in input.iter().enumerate() {
-            match self.process_line(&mut stack, line) {
+        for (i, line) in input.iter().enumerate() {
+            let mut stack = vec![];
+            match self
+                .process_line(&mut stack, line)
+                .wrap_err_with(|| format!("on line {}", i + 1))?
+            {
                 Ok(_) => (),
                 Err(match_err) => {
                     //println!("err on line {}", i);
@@ -93,44 +113,27 @@ impl Day10 {
                 }
             }
         }
-        println!("final score: {}", error_score);
-
-        Ok(())
+        Ok(error_score.into())
     }
 
-    fn part_2(&mut self, raw_input: &str) -> Result<()> {
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
         let mut completion_scores = vec![];
-        let input = self.parse_input(raw_input)?;
         for (i, line) in input.iter().enumerate() {
             let mut stack = vec![];
-            if self.process_line(&mut stack, line).is_ok() {
+            let processed = self
+                .process_line(&mut stack, line)
+                .wrap_err_with(|| format!("on line {}", i + 1))?;
+            if processed.is_ok() {
                 //println!("line {} is incomplete. Remaining items: {:?}", i, stack);
                 let completion_score = self.score_completion(&stack);
-                println!("completion score for {} is {}", i, completion_score);
+                debug!("completion score for {} is {}", i, completion_score);
                 completion_scores.push(completion_score);
             }
         }
         completion_scores.sort_unstable();
-        println!(
-            "middle score is {}",
-            completion_scores[completion_scores.len() / 2]
-        );
-        Ok(())
-    }
-}
-
-impl Day for Day10 {
-    fn run(&mut self) -> Result<()> {
-        println!("sample");
-        let sample_raw_input = read_to_string("src/data/day10-sample")?;
-        self.part_1(&sample_raw_input)?;
-        println!("part 1");
-        let raw_input = read_to_string("src/data/day10")?;
-        self.part_1(&raw_input)?;
-
-        println!("part 2!");
-        self.part_2(&sample_raw_input)?;
-        self.part_2(&raw_input)?;
-        Ok(())
+        let middle = completion_scores
+            .get(completion_scores.len() / 2)
+            .ok_or_else(|| eyre!("no line is incomplete"))?;
+        Ok((*middle).into())
     }
 }
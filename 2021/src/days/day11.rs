@@ -1,63 +1,35 @@
-use super::Day;
-use eyre::Result;
+use aoc_core::grid::{Grid, Point};
+use aoc_core::{register_day, Answer, Day};
+use eyre::{eyre, Result};
+use log::debug;
+use std::collections::HashSet;
 use std::fmt;
-use std::fs::read_to_string;
 
-struct OctoGrid {
-    grid: Vec<Vec<u32>>,
+#[derive(Clone)]
+pub struct OctoGrid {
+    grid: Grid<u32>,
 }
 
 impl fmt::Display for OctoGrid {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for line in &self.grid {
-            for val in line {
-                write!(f, " {:?}", val)?;
-            }
-            writeln!(f)?;
-        }
-        write!(f, "")
+        write!(f, "{}", self.grid)
     }
 }
 
 impl OctoGrid {
-    fn parse_input(raw_input: &str) -> Result<Vec<Vec<u32>>> {
-        Ok(raw_input
-            .lines()
-            .map(|l| {
-                l.chars()
-                    .map(|ch| ch.to_digit(10).unwrap())
-                    .collect::<Vec<_>>()
-            })
-            .collect::<Vec<_>>())
-    }
-
-    fn new(raw_input: &str) -> Self {
-        Self {
-            grid: Self::parse_input(raw_input).unwrap(),
-        }
+    pub fn new(raw_input: &str) -> Result<Self> {
+        Ok(Self {
+            grid: Grid::parse(raw_input)?,
+        })
     }
 
-    fn flash(&mut self, x: isize, y: isize) {
-        //println!("flashing {:?}", (x, y));
-        for dx in (x - 1)..=(x + 1) {
-            for dy in (y - 1)..=(y + 1) {
-                if dx < 0
-                    || dy < 0
-                    || dy >= self.grid.len() as isize
-                    || dx >= self.grid[0].len() as isize
-                {
-                    // out of bounds
-                    // println!("skipping {:?}", (dx, dy));
-                    continue;
-                }
-                //increase
-                self.grid[dx as usize][dy as usize] += 1;
-                //println!(
-                //"increased {:?} to {}",
-                //(dx as usize, dy as usize),
-                //self.grid[dx as usize][dy as usize]
-                //);
-            }
+    fn flash(&mut self, point: Point) {
+        //println!("flashing {:?}", point);
+        let neighbors: Vec<_> = self.grid.neighbors8(point).collect();
+        for neighbor in neighbors {
+            //increase
+            self.grid[neighbor] += 1;
+            //println!("increased {:?} to {}", neighbor, self.grid[neighbor]);
         }
     }
 
@@ -65,25 +37,23 @@ impl OctoGrid {
         let mut flashed = vec![];
         let mut total_flashes = 0;
         // add one to each item
-        self.grid.iter_mut().flatten().for_each(|i| *i += 1);
+        self.grid.values_mut().for_each(|i| *i += 1);
         //println!("after step 1:\n{}", self);
         // flash until no more flashes are possible
-        while self.grid.iter().flatten().any(|&i| i >= 10) {
+        while self.grid.values().any(|&i| i >= 10) {
             let mut flashed_in_attempt = 0;
             let grid_copy = self.grid.clone();
-            for x in 0..grid_copy.len() {
-                for y in 0..grid_copy[0].len() {
-                    if grid_copy[x][y] >= 10 {
-                        if flashed.contains(&(x, y)) {
-                            // flash at most once per step.
-                            continue;
-                        }
-                        // flash
-                        flashed_in_attempt += 1;
-                        flashed.push((x, y));
-                        total_flashes += 1;
-                        self.flash(x as isize, y as isize);
+            for (point, &energy) in grid_copy.iter() {
+                if energy >= 10 {
+                    if flashed.contains(&point) {
+                        // flash at most once per step.
+                        continue;
                     }
+                    // flash
+                    flashed_in_attempt += 1;
+                    flashed.push(point);
+                    total_flashes += 1;
+                    self.flash(point);
                 }
             }
             if flashed_in_attempt == 0 {
@@ -92,16 +62,11 @@ impl OctoGrid {
             }
         }
         // set all flashes back to zero
-        flashed.iter().for_each(|(x, y)| self.grid[*x][*y] = 0);
-        //self.grid
-        //.iter_mut()
-        //.flatten()
-        //.filter(|i| *i > &mut 9)
-        //.for_each(|i| *i = 0);
+        flashed.iter().for_each(|&point| self.grid[point] = 0);
         total_flashes
     }
 
-    fn step_n(&mut self, step_count: usize) -> usize {
+    pub fn step_n(&mut self, step_count: usize) -> usize {
         let mut total_flashes = 0;
         for _ in 0..step_count {
             let flash_count = self.step();
@@ -111,60 +76,56 @@ impl OctoGrid {
         total_flashes
     }
 
-    fn step_until_all_flash(&mut self) -> usize {
+    /// The first step on which every octopus flashes. Fails if the grid
+    /// gets back to a state it's been in before that happens.
+    pub fn step_until_all_flash(&mut self) -> Result<usize> {
+        let mut seen = HashSet::new();
         let mut step = 0;
-        loop {
+        while seen.insert(self.grid.clone()) {
             step += 1;
             let flash_count = self.step();
-            if flash_count == self.grid.len() * self.grid[0].len() {
+            if flash_count == self.grid.width() * self.grid.height() {
                 // all flashed
-                println!("all flashed on {}", step);
-                break;
+                debug!("all flashed on {}", step);
+                return Ok(step);
             }
         }
-        step
+        Err(eyre!(
+            "the octopuses repeat themselves after {} steps without all flashing at once",
+            step
+        ))
     }
 }
 
+#[derive(Default)]
 pub struct Day11 {}
 
+register_day!(2021, 11, Day11::new());
+
 impl Day11 {
     pub fn new() -> Self {
         Self {}
     }
+}
 
-    fn part_1(&mut self, raw_input: &str) -> Result<()> {
-        let mut grid = OctoGrid::new(raw_input);
-        //let total_flashes = grid.step_n(3);
-        let total_flashes = grid.step_n(100);
-        println!("total flashes: {}", total_flashes);
+impl Day for Day11 {
+    type Input<'a> = OctoGrid;
 
-        Ok(())
+    fn parse<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>> {
+        OctoGrid::new(raw_input)
     }
 
-    fn part_2(&mut self, raw_input: &str) -> Result<()> {
-        let mut grid = OctoGrid::new(raw_input);
+    fn part_1(&self, grid: &Self::Input<'_>) -> Result<Answer> {
+        let mut grid = grid.clone();
         //let total_flashes = grid.step_n(3);
-        let all_flashed_step = grid.step_until_all_flash();
-        println!("all flashes after : {}", all_flashed_step);
-
-        Ok(())
+        let total_flashes = grid.step_n(100);
+        Ok(total_flashes.into())
     }
-}
-
-impl Day for Day11 {
-    fn run(&mut self) -> Result<()> {
-        println!("sample");
-        let sample_raw_input = read_to_string("src/data/day11-sample")?;
-        //let sample_raw_input = read_to_string("src/data/day11-test")?;
-        self.part_1(&sample_raw_input)?;
-        println!("part 1");
-        let raw_input = read_to_string("src/data/day11")?;
-        self.part_1(&raw_input)?;
 
-        println!("part 2!");
-        self.part_2(&sample_raw_input)?;
-        self.part_2(&raw_input)?;
-        Ok(())
+    fn part_2(&self, grid: &Self::Input<'_>) -> Result<Answer> {
+        let mut grid = grid.clone();
+        //let total_flashes = grid.step_n(3);
+        let all_flashed_step = grid.step_until_all_flash()?;
+        Ok(all_flashed_step.into())
     }
 }
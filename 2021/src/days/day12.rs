@@ -1,24 +1,35 @@
-use super::Day;
+use aoc_core::{register_day, Answer, Day, ParseError, Part, Symbol};
 use eyre::Result;
 use itertools::Itertools;
-use std::fs::read_to_string;
 
-fn parse_input(raw_input: &str) -> Result<Vec<Vec<&str>>> {
-    Ok(raw_input
-        .lines()
-        .map(|l| l.split('-').collect::<Vec<_>>())
-        .collect::<Vec<_>>())
+pub struct Day12 {
+    start: Symbol,
+    end: Symbol,
 }
 
-pub struct Day12 {}
+register_day!(2021, 12, Day12::new());
+
+impl Default for Day12 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 impl Day12 {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            start: Symbol::new("start"),
+            end: Symbol::new("end"),
+        }
     }
 
-    fn should_revisit(&self, visited: &[String], destination: &str, small_cave_rule: bool) -> bool {
-        if destination == "start" {
+    fn should_revisit(
+        &self,
+        visited: &[Symbol],
+        destination: Symbol,
+        small_cave_rule: bool,
+    ) -> bool {
+        if destination == self.start {
             false
         } else if small_cave_rule {
             // no duplicates in visited
@@ -26,41 +37,41 @@ impl Day12 {
             if duplicates_in_visited {
                 // another cave was visited twice, thus
                 // this one cannot be visited twice.
-                !visited.contains(&destination.to_string())
+                !visited.contains(&destination)
             } else {
                 // this lowercase cave is the first one to
                 // be visited twice.
                 true
             }
         } else {
-            !visited.contains(&destination.to_string())
+            !visited.contains(&destination)
         }
     }
 
-    fn get_distinct_paths<'a>(
+    fn get_distinct_paths(
         &self,
-        current_location: &'a str,
-        cave_map: &[Vec<&'a str>],
-        visited: Vec<String>,
+        current_location: Symbol,
+        cave_map: &[Vec<Symbol>],
+        visited: Vec<Symbol>,
         depth: usize,
         small_cave_rule: bool,
-    ) -> Vec<Vec<&'a str>> {
+    ) -> Vec<Vec<Symbol>> {
         let new_depth = depth + 1;
         //println!(
         //"checking {:?} at {} with visited: {:?}",
         //current_location, new_depth, visited
         //);
-        return if current_location == "end" {
-            vec![vec!["end"]]
+        if current_location == self.end {
+            vec![vec![self.end]]
         } else {
-            let mut paths_from_here: Vec<Vec<&str>> = vec![];
+            let mut paths_from_here: Vec<Vec<Symbol>> = vec![];
             for link in cave_map {
                 let mut new_visited = visited.clone();
                 if link[0] == current_location
                     && self.should_revisit(&visited, link[1], small_cave_rule)
                 {
-                    if !link[1].chars().all(|c| c.is_uppercase()) {
-                        new_visited.push(link[1].to_string());
+                    if !link[1].as_str().chars().all(|c| c.is_uppercase()) {
+                        new_visited.push(link[1]);
                     }
                     let distinct_paths = self.get_distinct_paths(
                         link[1],
@@ -73,7 +84,7 @@ impl Day12 {
                     paths_from_here.extend(distinct_paths);
                 }
             }
-            let reverse_links: Vec<Vec<&str>> = cave_map
+            let reverse_links: Vec<Vec<Symbol>> = cave_map
                 .iter()
                 .map(|link| link.iter().rev().copied().collect())
                 .collect();
@@ -82,8 +93,8 @@ impl Day12 {
                 if link[0] == current_location
                     && self.should_revisit(&visited, link[1], small_cave_rule)
                 {
-                    if !link[1].chars().all(|c| c.is_uppercase()) {
-                        new_visited.push(link[1].to_string());
+                    if !link[1].as_str().chars().all(|c| c.is_uppercase()) {
+                        new_visited.push(link[1]);
                     }
                     let distinct_paths = self.get_distinct_paths(
                         link[1],
@@ -100,69 +111,50 @@ impl Day12 {
                 .iter_mut()
                 .for_each(|p| p.insert(0, current_location));
             paths_from_here
-        };
+        }
     }
 
-    fn count_distinct_paths(&self, cave_map: &[Vec<&str>]) -> usize {
-        let visited = vec!["start".to_string()];
+    fn count_distinct_paths(&self, cave_map: &[Vec<Symbol>]) -> usize {
+        let visited = vec![self.start];
         let depth = 0;
-        let paths = self.get_distinct_paths("start", cave_map, visited, depth, false);
+        let paths = self.get_distinct_paths(self.start, cave_map, visited, depth, false);
         //println!("paths were {:#?}", paths);
         paths.iter().unique().count()
     }
 
-    fn count_distinct_paths_2(&self, cave_map: &[Vec<&str>]) -> usize {
-        let visited = vec!["start".to_string()];
+    fn count_distinct_paths_2(&self, cave_map: &[Vec<Symbol>]) -> usize {
+        let visited = vec![self.start];
         let depth = 0;
-        let paths = self.get_distinct_paths("start", cave_map, visited, depth, true);
+        let paths = self.get_distinct_paths(self.start, cave_map, visited, depth, true);
         //println!("paths were {:#?}", paths);
         paths.iter().unique().count()
     }
+}
 
-    fn part_1(&mut self, raw_input: &str) -> Result<()> {
-        let cave_map = parse_input(raw_input)?;
-        let distinct_path_count = self.count_distinct_paths(&cave_map);
-        println!("there are {:?} distinct paths", distinct_path_count);
+impl Day for Day12 {
+    type Input<'a> = Vec<Vec<Symbol>>;
 
-        Ok(())
+    fn parse<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(raw_input
+            .lines()
+            .map(|l| match l.split_once('-') {
+                Some((from, to)) if !from.is_empty() && !to.is_empty() && !to.contains('-') => {
+                    Ok(vec![Symbol::new(from), Symbol::new(to)])
+                }
+                _ => Err(ParseError::at(raw_input, l, "a link like \"start-A\"")),
+            })
+            .collect::<Result<Vec<_>, _>>()?)
     }
 
-    fn part_2(&mut self, raw_input: &str) -> Result<()> {
-        let cave_map = parse_input(raw_input)?;
-        let distinct_path_count = self.count_distinct_paths_2(&cave_map);
-        println!("there are {:?} distinct paths", distinct_path_count);
-
-        Ok(())
+    fn part_1(&self, cave_map: &Self::Input<'_>) -> Result<Answer> {
+        Ok(self.count_distinct_paths(cave_map).into())
     }
-}
 
-impl Day for Day12 {
-    fn run(&mut self) -> Result<()> {
-        println!("sample");
-        let sample_raw_input = read_to_string("src/data/day12-sample")?;
-        self.part_1(&sample_raw_input)?;
-        println!("sample2");
-        let sample_raw_input = read_to_string("src/data/day12-sample2")?;
-        self.part_1(&sample_raw_input)?;
-        println!("sample3");
-        let sample_raw_input = read_to_string("src/data/day12-sample3")?;
-        self.part_1(&sample_raw_input)?;
-        println!("part 1");
-        let raw_input = read_to_string("src/data/day12")?;
-        self.part_1(&raw_input)?;
+    fn part_2(&self, cave_map: &Self::Input<'_>) -> Result<Answer> {
+        Ok(self.count_distinct_paths_2(cave_map).into())
+    }
 
-        println!("part 2!");
-        println!("sample");
-        let sample_raw_input = read_to_string("src/data/day12-sample")?;
-        self.part_2(&sample_raw_input)?;
-        println!("sample2");
-        let sample_raw_input = read_to_string("src/data/day12-sample2")?;
-        self.part_2(&sample_raw_input)?;
-        println!("sample3");
-        let sample_raw_input = read_to_string("src/data/day12-sample3")?;
-        self.part_2(&sample_raw_input)?;
-        println!("final");
-        self.part_2(&raw_input)?;
-        Ok(())
+    fn samples(&self, _part: Part) -> &'static [u8] {
+        &[1, 2, 3]
     }
 }
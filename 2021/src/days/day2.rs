@@ -1,65 +1,63 @@
-use super::Day;
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day, ParseError};
 use eyre::Result;
-use std::fs::read_to_string;
-use std::str::FromStr;
+use log::debug;
 
 pub struct Day2 {}
 
-impl Day2 {
-    fn part_1(&self, raw_input: &str) -> Result<()> {
+register_day!(2021, 2, Day2 {});
+
+impl Day for Day2 {
+    /// (direction, amount) for each command
+    type Input<'a> = Vec<(&'a str, u32)>;
+
+    fn parse<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(raw_input
+            .trim()
+            .lines()
+            .map(|line| {
+                let (direction, amount) = line
+                    .split_once(' ')
+                    .ok_or_else(|| ParseError::at(raw_input, line, "a direction and an amount"))?;
+                if !["forward", "down", "up"].contains(&direction) {
+                    return Err(ParseError::at(raw_input, direction, "forward, down or up"));
+                }
+                Ok((direction, number(raw_input, amount)?))
+            })
+            .collect::<Result<_, _>>()?)
+    }
+
+    fn part_1(&self, commands: &Self::Input<'_>) -> Result<Answer> {
         let mut horiz = 0;
         let mut depth = 0;
-        for line in raw_input.trim().lines() {
-            if let [direction, amount] = line.split(' ').collect::<Vec<_>>().as_slice() {
-                let amount_int = u32::from_str(amount)?;
-                match *direction {
-                    "forward" => horiz += amount_int,
-                    "down" => depth += amount_int,
-                    "up" => depth -= amount_int,
-                    _ => panic!("bad direction"),
-                }
-            };
+        for &(direction, amount_int) in commands {
+            match direction {
+                "forward" => horiz += amount_int,
+                "down" => depth += amount_int,
+                "up" => depth -= amount_int,
+                _ => unreachable!("parse checks the direction"),
+            }
         }
-        println!("horizontal position: {:?}, depth: {:?}", horiz, depth);
-        println!("final result: {}", horiz * depth);
-        Ok(())
+        debug!("horizontal position: {:?}, depth: {:?}", horiz, depth);
+        Ok((horiz * depth).into())
     }
 
-    fn part_2(&self, raw_input: &str) -> Result<()> {
+    fn part_2(&self, commands: &Self::Input<'_>) -> Result<Answer> {
         let mut horiz = 0;
         let mut depth = 0;
         let mut aim = 0;
-        for line in raw_input.trim().lines() {
-            if let [direction, amount] = line.split(' ').collect::<Vec<_>>().as_slice() {
-                let amount_int = u32::from_str(amount)?;
-                match *direction {
-                    "forward" => {
-                        horiz += amount_int;
-                        depth += aim * amount_int;
-                    }
-                    "down" => aim += amount_int,
-                    "up" => aim -= amount_int,
-                    _ => panic!("bad direction"),
+        for &(direction, amount_int) in commands {
+            match direction {
+                "forward" => {
+                    horiz += amount_int;
+                    depth += aim * amount_int;
                 }
-            };
+                "down" => aim += amount_int,
+                "up" => aim -= amount_int,
+                _ => unreachable!("parse checks the direction"),
+            }
         }
-        println!("horizontal position: {:?}, depth: {:?}", horiz, depth);
-        println!("final result: {}", horiz * depth);
-        Ok(())
-    }
-}
-
-impl Day for Day2 {
-    fn run(&mut self) -> Result<()> {
-        println!("sample");
-        let sample_raw_input = read_to_string("src/data/day2-sample")?;
-        self.part_1(&sample_raw_input)?;
-        println!("part 1");
-        let raw_input = read_to_string("src/data/day2-part1")?;
-        self.part_1(&raw_input)?;
-
-        println!("part 2!");
-        self.part_2(&sample_raw_input)?;
-        self.part_2(&raw_input)
+        debug!("horizontal position: {:?}, depth: {:?}", horiz, depth);
+        Ok((horiz * depth).into())
     }
 }
@@ -1,10 +1,12 @@
-use super::Day;
-use eyre::Result;
+use aoc_core::{register_day, Answer, Day, ParseError};
+use eyre::{eyre, Result};
+use log::debug;
 use std::cmp::Ordering;
-use std::fs::read_to_string;
 
 pub struct Day3 {}
 
+register_day!(2021, 3, Day3 {});
+
 impl Day3 {
     /// Assemble a vec of most common bits, where the
     /// index in the vec corresponds to the left-aligned
@@ -35,34 +37,56 @@ impl Day3 {
         }
         result
     }
+}
+
+impl Day for Day3 {
+    type Input<'a> = Vec<&'a str>;
 
-    fn part_1(&self, raw_input: &str) -> Result<()> {
-        let lines = raw_input.trim().lines().collect::<Vec<_>>();
+    fn parse<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>> {
+        let lines: Vec<&str> = raw_input.trim().lines().collect();
+        let width = lines.first().map_or(0, |line| line.len());
+        if width == 0 {
+            return Err(ParseError::end(raw_input, "a diagnostic report").into());
+        }
+        for line in &lines {
+            if let Some(i) = line.find(|c| c != '0' && c != '1') {
+                let ch = line[i..].chars().next().unwrap();
+                let bit = &line[i..i + ch.len_utf8()];
+                return Err(ParseError::at(raw_input, bit, "0 or 1").into());
+            }
+            if line.len() != width {
+                let expected = format!("a number {} bits wide", width);
+                return Err(ParseError::at(raw_input, line, expected).into());
+            }
+        }
+        Ok(lines)
+    }
+
+    fn part_1(&self, lines: &Self::Input<'_>) -> Result<Answer> {
         // set most common bit for each column
-        let most_common_bits = self.get_mcbs(&lines);
+        let most_common_bits = self.get_mcbs(lines);
         let mut gamma_rate = 0;
-        println!("most_common_bits: {:?}", most_common_bits);
-        for bit in &most_common_bits {
+        debug!("most_common_bits: {:?}", most_common_bits);
+        for (i, bit) in most_common_bits.iter().enumerate() {
+            let bit = bit.ok_or_else(|| eyre!("0 and 1 are equally common in bit {}", i))?;
             gamma_rate <<= 1;
-            gamma_rate |= bit.unwrap();
+            gamma_rate |= bit;
         }
-        println!("gamma_rate: {} b{:b}", gamma_rate, gamma_rate);
+        debug!("gamma_rate: {} b{:b}", gamma_rate, gamma_rate);
         // create a bitmask to avoid unnecessary ones
         let mut mask = 0;
         for _ in 0..most_common_bits.len() {
             mask <<= 1;
             mask |= 1;
         }
-        println!("mask: {:b}", mask);
+        debug!("mask: {:b}", mask);
         let epsilon_rate = !gamma_rate & mask;
-        println!("epsilon_rate: {} b{:b}", epsilon_rate, epsilon_rate);
-        println!("final result: {}", epsilon_rate * gamma_rate);
-        Ok(())
+        debug!("epsilon_rate: {} b{:b}", epsilon_rate, epsilon_rate);
+        Ok((epsilon_rate * gamma_rate).into())
     }
 
-    fn part_2(&self, raw_input: &str) -> Result<()> {
-        let lines = raw_input.trim().lines().collect::<Vec<_>>();
-        println!("lines: {:?}", lines);
+    fn part_2(&self, lines: &Self::Input<'_>) -> Result<Answer> {
+        debug!("lines: {:?}", lines);
         let mut oxygen_generator_rating = None;
         let mut c02_scrubber_rating = None;
         let mut oxygen_generator_vals = lines.clone();
@@ -78,12 +102,14 @@ impl Day3 {
                 oxygen_generator_vals = oxygen_generator_vals
                     .into_iter()
                     .filter(|line| {
-                        let mcb = if let Some(bit_val) = bit { bit_val } else { 1 };
+                        let mcb = bit.unwrap_or(1);
                         line.chars().collect::<Vec<_>>()[i]
                             == char::from_digit(mcb as u32, 10).unwrap()
                     })
                     .collect::<Vec<_>>();
-                if oxygen_generator_vals.len() == 1 {
+                if oxygen_generator_vals.is_empty() {
+                    return Err(eyre!("no oxygen generator rating left after bit {}", i));
+                } else if oxygen_generator_vals.len() == 1 {
                     // found it
 
                     oxygen_generator_rating =
@@ -101,12 +127,14 @@ impl Day3 {
                 c02_scrubber_vals = c02_scrubber_vals
                     .into_iter()
                     .filter(|line| {
-                        let mcb = if let Some(bit_val) = bit { bit_val } else { 1 };
+                        let mcb = bit.unwrap_or(1);
                         let not_bit = if mcb == 0 { '1' } else { '0' };
                         line.chars().collect::<Vec<_>>()[i] == not_bit
                     })
                     .collect::<Vec<_>>();
-                if c02_scrubber_vals.len() == 1 {
+                if c02_scrubber_vals.is_empty() {
+                    return Err(eyre!("no CO2 scrubber rating left after bit {}", i));
+                } else if c02_scrubber_vals.len() == 1 {
                     // found it
                     c02_scrubber_rating =
                         Some(u32::from_str_radix(c02_scrubber_vals[0], 2).unwrap());
@@ -119,25 +147,10 @@ impl Day3 {
                 //);
             }
         }
-        println!(
-            "final result: {}",
-            oxygen_generator_rating.unwrap() * c02_scrubber_rating.unwrap()
-        );
-        Ok(())
-    }
-}
-
-impl Day for Day3 {
-    fn run(&mut self) -> Result<()> {
-        println!("sample");
-        let sample_raw_input = read_to_string("src/data/day3-sample")?;
-        self.part_1(&sample_raw_input)?;
-        println!("part 1");
-        let raw_input = read_to_string("src/data/day3")?;
-        self.part_1(&raw_input)?;
-
-        println!("part 2!");
-        self.part_2(&sample_raw_input)?;
-        self.part_2(&raw_input)
+        let oxygen_generator_rating = oxygen_generator_rating
+            .ok_or_else(|| eyre!("more than one oxygen generator rating is left"))?;
+        let c02_scrubber_rating = c02_scrubber_rating
+            .ok_or_else(|| eyre!("more than one CO2 scrubber rating is left"))?;
+        Ok((oxygen_generator_rating * c02_scrubber_rating).into())
     }
 }
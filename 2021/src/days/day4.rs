@@ -1,10 +1,13 @@
-use super::Day;
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day, ParseError};
 use eyre::Result;
+use log::debug;
 use std::fmt;
-use std::fs::read_to_string;
 
 pub struct Day4 {}
 
+register_day!(2021, 4, Day4 {});
+
 #[derive(Debug, Clone)]
 struct NumberEntry {
     val: u32,
@@ -29,22 +32,30 @@ struct Board {
 }
 
 impl Board {
-    fn new(raw_board: &str) -> Self {
+    /// Parse a board from `raw_board`, a section of `raw_input`.
+    fn new(raw_input: &str, raw_board: &str) -> Result<Self, ParseError> {
         let mut entries = vec![];
         let rows = raw_board.split('\n').collect::<Vec<_>>();
         let height = rows.len();
         let width = rows[0].split_whitespace().count();
         for row in rows {
-            for num in row.trim().split_whitespace() {
-                entries.push(NumberEntry::new(num.parse::<u32>().unwrap()));
+            if row.split_whitespace().count() != width {
+                let expected = format!("a row of {} numbers", width);
+                return Err(ParseError::at(raw_input, row, expected));
+            }
+            for num in row.split_whitespace() {
+                entries.push(NumberEntry::new(number(raw_input, num)?));
             }
         }
-        Board {
+        if width == 0 {
+            return Err(ParseError::at(raw_input, raw_board, "a board of numbers"));
+        }
+        Ok(Board {
             numbers: entries,
             height,
             width,
             won: false,
-        }
+        })
     }
 
     fn update(&mut self, num: u32) {
@@ -85,7 +96,7 @@ impl Board {
             .filter(|e| !e.marked)
             .map(|e| e.val)
             .sum();
-        println!(
+        debug!(
             "calculating final score with {} * {} = {}",
             sum,
             last_num,
@@ -108,25 +119,36 @@ impl fmt::Display for Board {
     }
 }
 
-impl Day4 {
-    fn part_1(&self, raw_input: &str) -> Result<()> {
+/// The numbers to draw, in order, and the boards in play.
+pub struct Bingo {
+    drawings: Vec<u32>,
+    boards: Vec<Board>,
+}
+
+impl Day for Day4 {
+    type Input<'a> = Bingo;
+
+    fn parse<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>> {
         let mut entries = raw_input.trim().split("\n\n");
-        let drawings = entries.next().unwrap().split(',').collect::<Vec<_>>();
-        //println!("drawings: {:?}", drawings);
-        let raw_boards = entries.collect::<Vec<_>>();
-        let mut boards = vec![];
-        for raw_board in raw_boards {
-            let new_board = Board::new(raw_board);
-            boards.push(new_board);
-        }
+        let drawings = entries
+            .next()
+            .unwrap_or_default()
+            .split(',')
+            .map(|num_str| number(raw_input, num_str))
+            .collect::<Result<Vec<_>, _>>()?;
+        let boards = entries
+            .map(|raw_board| Board::new(raw_input, raw_board))
+            .collect::<Result<_, _>>()?;
+        Ok(Bingo { drawings, boards })
+    }
+
+    fn part_1(&self, bingo: &Self::Input<'_>) -> Result<Answer> {
+        let mut boards = bingo.boards.clone();
         // println!("boards: {:#?}", boards);
 
         let mut winning_board = None;
         let mut last_drawn_number = None;
-        for drawn_number in drawings
-            .iter()
-            .map(|num_str| num_str.parse::<u32>().unwrap())
-        {
+        for &drawn_number in &bingo.drawings {
             //println!("drawing: {}", drawn_number);
             for board in &mut boards {
                 board.update(drawn_number);
@@ -145,37 +167,23 @@ impl Day4 {
             }
         }
         if let Some(winner) = winning_board {
-            println!(
-                "final score: {}",
-                winner.final_score(last_drawn_number.unwrap())
-            );
+            Ok(winner.final_score(last_drawn_number.unwrap()).into())
         } else {
             panic!("no winner");
         }
-        Ok(())
     }
 
-    fn part_2(&self, raw_input: &str) -> Result<()> {
-        let mut entries = raw_input.trim().split("\n\n");
-        let drawings = entries.next().unwrap().split(',').collect::<Vec<_>>();
-        println!("drawings: {:?}", drawings);
-        let raw_boards = entries.collect::<Vec<_>>();
-        let mut boards = vec![];
-        for raw_board in raw_boards {
-            let new_board = Board::new(raw_board);
-            boards.push(new_board);
-        }
+    fn part_2(&self, bingo: &Self::Input<'_>) -> Result<Answer> {
+        debug!("drawings: {:?}", bingo.drawings);
+        let mut boards = bingo.boards.clone();
         // println!("boards: {:#?}", boards);
 
         let mut last_winning_board = None;
         let mut last_drawn_number = None;
         let mut winning_boards = 0;
         let mut last_winner_ready = false;
-        for drawn_number in drawings
-            .iter()
-            .map(|num_str| num_str.parse::<u32>().unwrap())
-        {
-            println!("drawing: {}", drawn_number);
+        for &drawn_number in &bingo.drawings {
+            debug!("drawing: {}", drawn_number);
 
             let board_count = boards.len();
             for board in &mut boards {
@@ -188,10 +196,10 @@ impl Day4 {
                         last_winner_ready = true;
                         last_winning_board = Some(board.clone());
                         last_drawn_number = Some(drawn_number);
-                        println!("last winning board: \n{}", board);
+                        debug!("last winning board: \n{}", board);
                         break;
                     }
-                    println!("board won: \n{}", board);
+                    debug!("board won: \n{}", board);
                     winning_boards += 1;
                     board.won = true;
                 }
@@ -201,28 +209,9 @@ impl Day4 {
             }
         }
         if let Some(winner) = last_winning_board {
-            println!(
-                "final score: {}",
-                winner.final_score(last_drawn_number.unwrap())
-            );
+            Ok(winner.final_score(last_drawn_number.unwrap()).into())
         } else {
             panic!("no winner");
         }
-        Ok(())
-    }
-}
-
-impl Day for Day4 {
-    fn run(&mut self) -> Result<()> {
-        println!("sample");
-        let sample_raw_input = read_to_string("src/data/day4-sample")?;
-        self.part_1(&sample_raw_input)?;
-        println!("part 1");
-        let raw_input = read_to_string("src/data/day4")?;
-        self.part_1(&raw_input)?;
-
-        println!("part 2!");
-        self.part_2(&sample_raw_input)?;
-        self.part_2(&raw_input)
     }
 }
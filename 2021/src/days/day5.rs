@@ -1,11 +1,15 @@
-use super::Day;
+use aoc_core::grid::Grid;
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day, ParseError};
 use eyre::Result;
+use log::debug;
 use std::cmp::{max, min};
 use std::fmt;
-use std::fs::read_to_string;
 
 pub struct Day5 {}
 
+register_day!(2021, 5, Day5 {});
+
 fn get_range_for(x1: i32, x2: i32) -> Box<dyn Iterator<Item = i32>> {
     if x1 > x2 {
         //println!("{}-{}", x2, x1);
@@ -27,10 +31,18 @@ impl VentEntry {
     }
 }
 
+impl fmt::Display for VentEntry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.line_count > 0 {
+            write!(f, " {} ", self.line_count)
+        } else {
+            write!(f, " . ")
+        }
+    }
+}
+
 struct VentMap {
-    locations: Vec<VentEntry>,
-    width: usize,
-    height: usize,
+    locations: Grid<VentEntry>,
     version: u8,
 }
 
@@ -42,36 +54,29 @@ impl VentMap {
         version: Option<u8>,
     ) -> Self {
         let mut result = VentMap {
-            locations: vec![VentEntry::new(); width * height],
-            width,
-            height,
+            locations: Grid::new(width, height, VentEntry::new()),
             version: version.unwrap_or(1),
         };
         for segment in segments {
-            println!("working on {:?}", segment);
+            debug!("working on {:?}", segment);
             let ((x1, y1), (x2, y2)) = (segment.0, segment.1);
             if x1 == x2 {
                 // vertical
                 //println!("vertical {},{}-{}", x1, y1, y2);
                 for y in min(y1, y2)..max(y1, y2) + 1 {
-                    let mut entry = result.at_mut(x1 as usize, y as usize);
-                    entry.line_count += 1;
-                    //println!("got: {:?}", entry);
+                    result.locations[(x1 as usize, y as usize)].line_count += 1;
                 }
             } else if y1 == y2 {
                 //horizontal
                 //println!("horizontal {}-{},{}", x1, x2, y1);
                 for x in min(x1, x2)..max(x1, x2) + 1 {
-                    let mut entry = result.at_mut(x as usize, y1 as usize);
-                    entry.line_count += 1;
-                    //println!("got: {:?}", entry);
+                    result.locations[(x as usize, y1 as usize)].line_count += 1;
                 }
             } else if result.version == 2 && (x2 - x1).abs() == (y2 - y1).abs() {
                 // 45 degree diagonal line
                 for (x, y) in (get_range_for(x1, x2)).zip(get_range_for(y1, y2)) {
-                    println!("{},{}", x, y);
-                    let mut entry = result.at_mut(x as usize, y as usize);
-                    entry.line_count += 1;
+                    debug!("{},{}", x, y);
+                    result.locations[(x as usize, y as usize)].line_count += 1;
                 }
             } else {
                 //println!("diagonal line");
@@ -81,20 +86,11 @@ impl VentMap {
         result
     }
 
-    fn at(&self, x: usize, y: usize) -> &VentEntry {
-        &self.locations[y * self.width + x]
-    }
-
-    fn at_mut(&mut self, x: usize, y: usize) -> &mut VentEntry {
-        //println!("trying to get {},{}", x, y);
-        &mut self.locations[y * self.width + x]
-    }
-
     /// Count the number of entries in the map with more
     /// than num lines passing through them.
     fn count_locations_with_at_least(&self, num: u32) -> usize {
         self.locations
-            .iter()
+            .values()
             .filter(|e| e.line_count >= num)
             .count()
     }
@@ -102,138 +98,72 @@ impl VentMap {
 
 impl fmt::Display for VentMap {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for y in 0..self.height {
-            for x in 0..self.width {
-                if self.at(x, y).line_count > 0 {
-                    write!(f, " {} ", self.at(x, y).line_count)?;
-                } else {
-                    write!(f, " . ")?;
-                }
-            }
-            writeln!(f)?;
-        }
-        Ok(())
+        write!(f, "{}", self.locations)
     }
 }
 
-impl Day5 {
-    #[allow(dead_code)]
-    fn run(&mut self) -> Result<()> {
-        Ok(())
-    }
+/// Line segments along with the size of the map they're drawn on.
+pub struct Vents {
+    segments: Vec<((i32, i32), (i32, i32))>,
+    width: usize,
+    height: usize,
 }
 
-impl Day5 {
-    fn part_1(&self, raw_input: &str) -> Result<()> {
-        let raw_nums = raw_input
-            .trim()
-            .split_whitespace()
-            .filter(|i| i != &"->")
-            .flat_map(|i| i.split(',').collect::<Vec<_>>());
-        let max_x = raw_nums
-            .clone()
-            .step_by(2)
-            .max()
-            .map(|i| i.parse::<usize>().unwrap())
-            .unwrap()
-            + 1;
-        let max_y = raw_nums
-            .clone()
-            .skip(1)
-            .step_by(2)
-            .max()
-            .map(|i| i.parse::<usize>().unwrap())
-            .unwrap()
-            + 1;
-        println!("x max: {}, y max: {}", max_x, max_y);
-        //println!("raw nums: {:?}", raw_nums.collect::<Vec<_>>());
-        let entries = raw_input
+impl Day for Day5 {
+    type Input<'a> = Vents;
+
+    fn parse<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>> {
+        let point = |raw: &str| -> Result<(i32, i32), ParseError> {
+            let (x, y) = raw
+                .split_once(',')
+                .ok_or_else(|| ParseError::at(raw_input, raw, "a point like 0,9"))?;
+            let point = (number(raw_input, x)?, number(raw_input, y)?);
+            if point.0 < 0 || point.1 < 0 {
+                return Err(ParseError::at(raw_input, raw, "a point with no negatives"));
+            }
+            Ok(point)
+        };
+        let segments = raw_input
             .trim()
             .split('\n')
             .map(|e| {
-                let items = e
-                    .split_whitespace()
-                    .filter(|i| i != &"->")
-                    .map(|i| {
-                        let pair = i
-                            .split(',')
-                            .map(|n| n.parse::<i32>().unwrap())
-                            .collect::<Vec<_>>();
-                        (pair[0], pair[1])
-                    })
-                    .collect::<Vec<(i32, i32)>>();
-                //println!("items: {:?}", items);
-                (items[0], items[1])
+                let (start, end) = e
+                    .split_once(" -> ")
+                    .ok_or_else(|| ParseError::at(raw_input, e, "a line like 0,9 -> 5,9"))?;
+                //println!("items: {:?} {:?}", start, end);
+                Ok((point(start)?, point(end.trim())?))
             })
-            .collect::<Vec<_>>();
-
-        let map = VentMap::new(entries, max_x, max_y, None);
-        //println!("{}", map);
-        println!("final result: {}", map.count_locations_with_at_least(2));
-        Ok(())
-    }
-
-    fn part_2(&self, raw_input: &str) -> Result<()> {
-        let raw_nums = raw_input
-            .trim()
-            .split_whitespace()
-            .filter(|i| i != &"->")
-            .flat_map(|i| i.split(',').collect::<Vec<_>>());
-        let max_x = raw_nums
-            .clone()
-            .step_by(2)
+            .collect::<Result<Vec<_>, ParseError>>()?;
+        let max_x = segments
+            .iter()
+            .map(|(start, end)| max(start.0, end.0))
             .max()
-            .map(|i| i.parse::<usize>().unwrap())
-            .unwrap()
+            .ok_or_else(|| ParseError::end(raw_input, "a line of vents"))?
+            as usize
             + 1;
-        let max_y = raw_nums
-            .clone()
-            .skip(1)
-            .step_by(2)
+        let max_y = segments
+            .iter()
+            .map(|(start, end)| max(start.1, end.1))
             .max()
-            .map(|i| i.parse::<usize>().unwrap())
-            .unwrap()
+            .unwrap_or_default() as usize
             + 1;
-        println!("x max: {}, y max: {}", max_x, max_y);
-        //println!("raw nums: {:?}", raw_nums.collect::<Vec<_>>());
-        let entries = raw_input
-            .trim()
-            .split('\n')
-            .map(|e| {
-                let items = e
-                    .split_whitespace()
-                    .filter(|i| i != &"->")
-                    .map(|i| {
-                        let pair = i
-                            .split(',')
-                            .map(|n| n.parse::<i32>().unwrap())
-                            .collect::<Vec<_>>();
-                        (pair[0], pair[1])
-                    })
-                    .collect::<Vec<(i32, i32)>>();
-                //println!("items: {:?}", items);
-                (items[0], items[1])
-            })
-            .collect::<Vec<_>>();
-
-        let map = VentMap::new(entries, max_x, max_y, Some(2));
-        println!("{}", map);
-        println!("final result: {}", map.count_locations_with_at_least(2));
-        Ok(())
+        debug!("x max: {}, y max: {}", max_x, max_y);
+        Ok(Vents {
+            segments,
+            width: max_x,
+            height: max_y,
+        })
     }
-}
 
-impl Day for Day5 {
-    fn run(&mut self) -> Result<()> {
-        println!("sample");
-        let sample_raw_input = read_to_string("src/data/day5-sample")?;
-        self.part_1(&sample_raw_input)?;
-        println!("part 1");
-        let raw_input = read_to_string("src/data/day5")?;
-        self.part_1(&raw_input)?;
+    fn part_1(&self, vents: &Self::Input<'_>) -> Result<Answer> {
+        let map = VentMap::new(vents.segments.clone(), vents.width, vents.height, None);
+        //println!("{}", map);
+        Ok(map.count_locations_with_at_least(2).into())
+    }
 
-        println!("part 2!");
-        self.part_2(&sample_raw_input)?;
-        self.part_2(&raw_input)
+    fn part_2(&self, vents: &Self::Input<'_>) -> Result<Answer> {
+        let map = VentMap::new(vents.segments.clone(), vents.width, vents.height, Some(2));
+        debug!("{}", map);
+        Ok(map.count_locations_with_at_least(2).into())
     }
 }
@@ -1,15 +1,16 @@
-use super::Day;
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day};
 use eyre::Result;
-use std::fs::read_to_string;
+use log::debug;
 
 const NEW_FISH_DAYS: usize = 8;
 
-struct SeaFloor {
+pub struct SeaFloor {
     fish: [usize; NEW_FISH_DAYS + 1],
 }
 
 impl SeaFloor {
-    fn new(init: Option<&[usize]>) -> Self {
+    pub fn new(init: Option<&[usize]>) -> Self {
         let mut fish = [0; NEW_FISH_DAYS + 1];
         if let Some(items) = init {
             for &item in items {
@@ -19,7 +20,7 @@ impl SeaFloor {
         SeaFloor { fish }
     }
 
-    fn pass_days(&mut self, count: u32) -> Result<()> {
+    pub fn pass_days(&mut self, count: u32) -> Result<()> {
         for _ in 0..count {
             self.fish.rotate_left(1);
             self.fish[6] += self.fish[NEW_FISH_DAYS];
@@ -32,61 +33,37 @@ impl SeaFloor {
 
 pub struct Day6 {}
 
-impl Day6 {
-    fn parse_input(&self, raw_input: &str) -> Vec<usize> {
+register_day!(2021, 6, Day6 {});
+
+impl Day for Day6 {
+    type Input<'a> = Vec<usize>;
+
+    fn parse<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>> {
         let initial_state = raw_input
             .trim()
             .split(',')
             .map(|i| {
                 //println!("{:?}", i);
-                i.parse::<usize>().unwrap()
+                number::<usize>(raw_input, i)
             })
-            .collect::<Vec<_>>();
-        println!("initial state: {:?}", initial_state);
-        initial_state
+            .collect::<Result<Vec<_>, _>>()?;
+        debug!("initial state: {:?}", initial_state);
+        Ok(initial_state)
     }
 
-    fn part_1(&self, raw_input: &str) -> Result<()> {
-        let initial_state = self.parse_input(raw_input);
-        let mut floor = SeaFloor::new(Some(&initial_state));
+    fn part_1(&self, initial_state: &Self::Input<'_>) -> Result<Answer> {
+        let mut floor = SeaFloor::new(Some(initial_state));
         let days = 80;
 
         floor.pass_days(days)?;
-        println!(
-            "{} fish after {} days",
-            floor.fish.iter().sum::<usize>(),
-            days
-        );
-        Ok(())
+        Ok(floor.fish.iter().sum::<usize>().into())
     }
 
-    fn part_2(&self, raw_input: &str) -> Result<()> {
-        let initial_state = self.parse_input(raw_input);
-        let mut floor = SeaFloor::new(Some(&initial_state));
+    fn part_2(&self, initial_state: &Self::Input<'_>) -> Result<Answer> {
+        let mut floor = SeaFloor::new(Some(initial_state));
         let days = 256;
 
         floor.pass_days(days)?;
-        println!(
-            "{} fish after {} days",
-            floor.fish.iter().sum::<usize>(),
-            days
-        );
-        Ok(())
-    }
-}
-
-impl Day for Day6 {
-    fn run(&mut self) -> Result<()> {
-        println!("sample");
-        let sample_raw_input = read_to_string("src/data/day6-sample")?;
-        self.part_1(&sample_raw_input)?;
-        println!("part 1");
-        let raw_input = read_to_string("src/data/day6")?;
-        self.part_1(&raw_input)?;
-
-        println!("part 2!");
-        self.part_2(&sample_raw_input)?;
-        self.part_2(&raw_input)?;
-        Ok(())
+        Ok(floor.fish.iter().sum::<usize>().into())
     }
 }
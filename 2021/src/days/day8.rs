@@ -1,10 +1,11 @@
-use super::Day;
+use aoc_core::{register_day, Answer, Day, ParseError};
 use eyre::Result;
 use std::collections::{HashMap, HashSet};
-use std::fs::read_to_string;
 
 pub struct Day8 {}
 
+register_day!(2021, 8, Day8 {});
+
 impl Day8 {
     fn get_digit_by_segment_count(&self, segment_count: usize) -> Option<usize> {
         match segment_count {
@@ -45,11 +46,37 @@ impl Day8 {
             0
         }
     }
+}
+
+impl Day for Day8 {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>> {
+        let lines: Vec<&str> = raw_input.trim().lines().collect();
+        for line in &lines {
+            let (patterns, output) = line
+                .split_once('|')
+                .ok_or_else(|| ParseError::at(raw_input, line, "signal patterns | output"))?;
+            for (section, count) in [(patterns, 10), (output, 4)] {
+                let signals: Vec<&str> = section.split_whitespace().collect();
+                if signals.len() != count {
+                    let expected = format!("{} signal patterns", count);
+                    return Err(ParseError::at(raw_input, section, expected).into());
+                }
+                if let Some(signal) = signals
+                    .iter()
+                    .find(|s| s.len() > 7 || !s.chars().all(|c| ('a'..='g').contains(&c)))
+                {
+                    return Err(ParseError::at(raw_input, signal, "segments a to g").into());
+                }
+            }
+        }
+        Ok(lines)
+    }
 
-    fn part_1(&self, raw_input: &str) -> Result<()> {
-        let unique_segment_count = raw_input
-            .trim()
-            .lines()
+    fn part_1(&self, lines: &Self::Input<'_>) -> Result<Answer> {
+        let unique_segment_count = lines
+            .iter()
             .map(|line| line.split('|').nth(1).unwrap())
             .flat_map(|line| {
                 line.split_whitespace()
@@ -58,17 +85,12 @@ impl Day8 {
                     .collect::<Vec<_>>()
             })
             .count();
-        println!(
-            "There are {} uniquely identifiable outputs.",
-            unique_segment_count
-        );
-
-        Ok(())
+        Ok(unique_segment_count.into())
     }
 
-    fn part_2(&self, raw_input: &str) -> Result<()> {
+    fn part_2(&self, lines: &Self::Input<'_>) -> Result<Answer> {
         let mut running_sum = 0;
-        for line in raw_input.trim().lines() {
+        for line in lines {
             let mut signal_map = HashMap::new();
             // find 1,4,7
             line.split_whitespace()
@@ -112,24 +134,6 @@ impl Day8 {
             let folded_val = output_for_line.iter().fold(0, |acc, elem| acc * 10 + elem);
             running_sum += folded_val;
         }
-        println!("final sum is {}", running_sum);
-
-        Ok(())
-    }
-}
-
-impl Day for Day8 {
-    fn run(&mut self) -> Result<()> {
-        println!("sample");
-        let sample_raw_input = read_to_string("src/data/day8-sample")?;
-        self.part_1(&sample_raw_input)?;
-        println!("part 1");
-        let raw_input = read_to_string("src/data/day8")?;
-        self.part_1(&raw_input)?;
-
-        println!("part 2!");
-        self.part_2(&sample_raw_input)?;
-        self.part_2(&raw_input)?;
-        Ok(())
+        Ok(running_sum.into())
     }
 }
@@ -1,175 +1,89 @@
-use super::Day;
+use aoc_core::grid::{Grid, Point};
+use aoc_core::{register_day, Answer, Day};
 use eyre::Result;
+use log::debug;
 use std::collections::BinaryHeap;
-use std::fs::read_to_string;
 
-pub struct Day9 {
-    map: Vec<(usize, Vec<(usize, u32)>)>,
-}
+#[derive(Default)]
+pub struct Day9 {}
+
+register_day!(2021, 9, Day9::new());
 
 impl Day9 {
     pub fn new() -> Self {
-        Self { map: vec![] }
+        Self {}
     }
+}
 
-    fn get_neighbor_indices(&self, i: usize, j: usize) -> Vec<(usize, usize)> {
-        let len_i = self.map.len() - 1;
-        let len_j = self.map[0].1.len() - 1;
-        let mut res = vec![];
-
-        if i > 0 {
-            res.push((i - 1, j));
-        }
-        if i < len_i {
-            res.push((i + 1, j));
-        }
-        if j > 0 {
-            res.push((i, j - 1));
-        }
-        if j < len_j {
-            res.push((i, j + 1));
-        }
-        res
-    }
+pub struct HeightMap {
+    map: Grid<u32>,
+}
 
-    fn sum_basin_neighbors(&self, i: usize, j: usize) -> usize {
+impl HeightMap {
+    fn sum_basin_neighbors(&self, point: Point) -> usize {
         let mut visited = vec![];
-        self.sum_basin_neighbors_recursive(i, j, &mut visited)
+        self.sum_basin_neighbors_recursive(point, &mut visited)
     }
 
     /// naming is hard and I'm not feeling creative
-    fn sum_basin_neighbors_recursive(
-        &self,
-        i: usize,
-        j: usize,
-        visited: &mut Vec<(usize, usize)>,
-    ) -> usize {
+    fn sum_basin_neighbors_recursive(&self, point: Point, visited: &mut Vec<Point>) -> usize {
         let mut sum = 1;
-        for (neighbor_i, neighbor_j) in self.get_neighbor_indices(i, j) {
-            let neighbor = self.map[neighbor_i].1[neighbor_j].1;
-            if neighbor != 9
-                && neighbor > self.map[i].1[j].1
-                && !visited.contains(&(neighbor_i, neighbor_j))
-            {
-                visited.push((neighbor_i, neighbor_j));
-                sum += self.sum_basin_neighbors_recursive(neighbor_i, neighbor_j, visited);
+        for neighbor_point in self.map.neighbors4(point) {
+            let neighbor = self.map[neighbor_point];
+            if neighbor != 9 && neighbor > self.map[point] && !visited.contains(&neighbor_point) {
+                visited.push(neighbor_point);
+                sum += self.sum_basin_neighbors_recursive(neighbor_point, visited);
             }
         }
         sum
     }
 
-    fn get_neighbors(&self, i: usize, j: usize) -> Vec<u32> {
-        let len_i = self.map.len() - 1;
-        let len_j = self.map[0].1.len() - 1;
-        let mut res = vec![];
-        if i > 0 {
-            // i-1, j
-            res.push(self.map[i - 1].1[j].1);
-            if j > 0 {
-                // i-1, j-1
-                res.push(self.map[i - 1].1[j - 1].1);
-            }
-            if j < len_j {
-                // i-1, j+1
-                res.push(self.map[i - 1].1[j + 1].1);
-            }
-        };
-        if j > 0 {
-            // i, j-1
-            res.push(self.map[i].1[j - 1].1);
-        }
-
-        if i < len_i {
-            // i+1, j
-            res.push(self.map[i + 1].1[j].1);
-            if j > 0 {
-                // i+1, j-1
-                res.push(self.map[i + 1].1[j - 1].1);
-            }
-            if j < len_j {
-                // i+1, j+1
-                res.push(self.map[i + 1].1[j + 1].1);
-            }
-        }
-        if j < len_j {
-            // i, j+1
-            res.push(self.map[i].1[j + 1].1);
-        }
-
-        res
+    fn get_neighbors(&self, point: Point) -> Vec<u32> {
+        self.map
+            .neighbors8(point)
+            .map(|neighbor| self.map[neighbor])
+            .collect()
     }
+}
+
+impl Day for Day9 {
+    type Input<'a> = HeightMap;
 
-    fn parse_input(&mut self, raw_input: &str) -> Result<()> {
-        self.map = raw_input
-            .lines()
-            .map(|l| {
-                l.chars()
-                    .map(|ch| ch.to_digit(10).unwrap())
-                    .enumerate()
-                    .collect::<Vec<_>>()
-            })
-            .enumerate()
-            .collect();
-        Ok(())
+    fn parse<'a>(&self, raw_input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(HeightMap {
+            map: Grid::parse(raw_input)?,
+        })
     }
 
-    fn part_1(&mut self, raw_input: &str) -> Result<()> {
+    fn part_1(&self, height_map: &Self::Input<'_>) -> Result<Answer> {
         let mut low_points = vec![];
-        self.parse_input(raw_input)?;
-        for (i, line) in &self.map {
-            for (j, height) in line {
-                let neighbors = self.get_neighbors(*i, *j);
-                //println!("{:?} has neighbors {:?}", height, neighbors);
-                if neighbors.iter().all(|i| i > height) {
-                    //println!("found low point in {:?}", (height, i, j));
-                    low_points.push(height + 1);
-                }
+        for (point, height) in height_map.map.iter() {
+            let neighbors = height_map.get_neighbors(point);
+            //println!("{:?} has neighbors {:?}", height, neighbors);
+            if neighbors.iter().all(|i| i > height) {
+                //println!("found low point in {:?}", (height, point));
+                low_points.push(height + 1);
             }
         }
-        println!("risk level sum is {}", low_points.iter().sum::<u32>());
-        Ok(())
+        Ok(low_points.iter().sum::<u32>().into())
     }
 
-    fn part_2(&mut self, raw_input: &str) -> Result<()> {
+    fn part_2(&self, height_map: &Self::Input<'_>) -> Result<Answer> {
         let mut low_points = vec![];
-        self.parse_input(raw_input)?;
-        for (i, line) in &self.map {
-            for (j, height) in line {
-                let neighbors = self.get_neighbors(*i, *j);
-                if neighbors.iter().all(|i| i > height) {
-                    low_points.push((height + 1, i, j));
-                }
+        for (point, height) in height_map.map.iter() {
+            let neighbors = height_map.get_neighbors(point);
+            if neighbors.iter().all(|i| i > height) {
+                low_points.push((height + 1, point));
             }
         }
 
         let mut basins = BinaryHeap::new();
-        for (lp_val, lp_i, lp_j) in low_points {
-            let basin_size = self.sum_basin_neighbors(*lp_i, *lp_j);
-            println!("low point {:?} has {:?} basin vals", lp_val, basin_size);
+        for (lp_val, lp_point) in low_points {
+            let basin_size = height_map.sum_basin_neighbors(lp_point);
+            debug!("low point {:?} has {:?} basin vals", lp_val, basin_size);
             basins.push(basin_size);
         }
-        println!("final basins: {:?}.", basins,);
-        println!(
-            "Top 3 product: {}",
-            basins.pop().unwrap() * basins.pop().unwrap() * basins.pop().unwrap()
-        );
-
-        Ok(())
-    }
-}
-
-impl Day for Day9 {
-    fn run(&mut self) -> Result<()> {
-        println!("sample");
-        let sample_raw_input = read_to_string("src/data/day9-sample")?;
-        self.part_1(&sample_raw_input)?;
-        println!("part 1");
-        let raw_input = read_to_string("src/data/day9")?;
-        self.part_1(&raw_input)?;
-
-        println!("part 2!");
-        self.part_2(&sample_raw_input)?;
-        self.part_2(&raw_input)?;
-        Ok(())
+        debug!("final basins: {:?}.", basins,);
+        Ok((basins.pop().unwrap() * basins.pop().unwrap() * basins.pop().unwrap()).into())
     }
 }
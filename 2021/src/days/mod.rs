@@ -1,5 +1,3 @@
-use eyre::Result;
-
 pub mod day1;
 pub mod day10;
 pub mod day11;
@@ -12,7 +10,3 @@ pub mod day6;
 pub mod day7;
 pub mod day8;
 pub mod day9;
-
-pub trait Day {
-    fn run(&mut self) -> Result<()>;
-}
@@ -0,0 +1,21 @@
+//! Where this year's inputs and solutions live. Shared with the build
+//! script, which generates a test for each sample from it.
+
+use aoc_core::{Layout, Source};
+
+/// Inputs live in `src/data` as `dayN`, with samples as `dayN-sample`,
+/// `dayN-sample2` and so on.
+pub const LAYOUT: Layout = Layout {
+    year: 2021,
+    dir: concat!(env!("CARGO_MANIFEST_DIR"), "/src/data"),
+    input: |day| format!("day{}", day),
+    sample: |day, n| match n {
+        1 => format!("day{}-sample", day),
+        _ => format!("day{}-sample{}", day, n),
+    },
+    source: Source {
+        dir: concat!(env!("CARGO_MANIFEST_DIR"), "/src"),
+        module: |day| format!("days/day{}.rs", day),
+        declarations: "days/mod.rs",
+    },
+};
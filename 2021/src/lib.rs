@@ -0,0 +1,11 @@
+//! Solutions to Advent of Code 2021, one module per day under [`days`].
+
+pub mod days;
+mod layout;
+
+pub use layout::LAYOUT;
+
+#[cfg(test)]
+mod sample_tests {
+    include!(concat!(env!("OUT_DIR"), "/sample_tests.rs"));
+}
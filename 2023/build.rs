@@ -0,0 +1,6 @@
+#[path = "src/layout.rs"]
+mod layout;
+
+fn main() {
+    aoc_core::sample_tests::generate(&layout::LAYOUT).unwrap();
+}
@@ -1,7 +1,8 @@
-use anyhow::Context;
-use anyhow::Result;
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day, ParseError, Part, Symbol};
+use eyre::Result;
 use lazy_static::lazy_static;
-use log::{debug, error, info};
+use log::{debug, error};
 use std::collections::BinaryHeap;
 use std::collections::HashMap;
 
@@ -9,110 +10,135 @@ use std::collections::HashMap;
 //
 lazy_static! {
     static ref MAP: HashMap<&'static str, usize> = [("red", 12), ("blue", 14), ("green", 13)]
-        .iter()
-        .copied()
+        .into_iter()
         .collect();
 }
 
-pub fn run() -> Result<()> {
-    cubes_1(
-        "sample",
-        &std::fs::read_to_string("src/sampledata/2.sample").unwrap(),
-    )?;
-    cubes_1(
-        "part 1",
-        &std::fs::read_to_string("src/sampledata/2.1").unwrap(),
-    )?;
-    cubes_2(
-        "sample",
-        &std::fs::read_to_string("src/sampledata/2.sample2").unwrap(),
-    )?;
-    cubes_2(
-        "part 2",
-        &std::fs::read_to_string("src/sampledata/2.1").unwrap(),
-    )?;
-    Ok(())
+pub struct Cubes;
+
+register_day!(2023, 2, Cubes);
+
+/// The cubes shown in one round, as (count, colour) pairs.
+pub type Round = Vec<(usize, Symbol)>;
+
+pub struct Game {
+    pub id: usize,
+    pub rounds: Vec<Round>,
 }
 
-fn cubes_1(name: &str, f: &str) -> Result<()> {
-    let sum: usize = f
+/// Parse lines like "Game 1: 3 blue, 4 red; 1 red, 2 green".
+pub fn parse_games(input: &str) -> Result<Vec<Game>, ParseError> {
+    input
         .lines()
-        .enumerate()
-        .filter_map(|(i, game)| {
-            let game_not_possible = game
-                .trim_start_matches(&format!("Game {}:", i + 1))
+        .map(|line| {
+            let (id, rounds) = line
+                .strip_prefix("Game ")
+                .and_then(|rest| rest.split_once(':'))
+                .ok_or_else(|| ParseError::at(input, line, "a line like \"Game 1: 3 blue\""))?;
+            let rounds = rounds
                 .split(';')
-                .any(|round| {
-                    round.split(',').any(|cube| {
-                        let entry = cube.trim().split(' ').collect::<Vec<_>>();
-                        let (count, color) = (
-                            entry[0]
-                                .parse::<usize>()
-                                .context(format!("unwrapping digit for {}", entry[0]))
-                                .unwrap(),
-                            entry[1],
-                        );
-                        *MAP.get(color).unwrap() < count
-                    })
-                });
+                .map(|round| {
+                    round
+                        .split(',')
+                        .map(|cube| {
+                            let cube = cube.trim();
+                            let (count, name) = cube.split_once(' ').ok_or_else(|| {
+                                ParseError::at(input, cube, "a count and a colour")
+                            })?;
+                            // only intern colours that exist
+                            if !MAP.contains_key(name) {
+                                return Err(ParseError::at(input, name, "red, green or blue"));
+                            }
+                            Ok((number(input, count)?, Symbol::new(name)))
+                        })
+                        .collect()
+                })
+                .collect::<Result<_, _>>()?;
+            Ok(Game {
+                id: number(input, id)?,
+                rounds,
+            })
+        })
+        .collect()
+}
+
+impl Day for Cubes {
+    type Input<'a> = Vec<Game>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(parse_games(input)?)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        cubes_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        cubes_2(input)
+    }
+
+    fn samples(&self, part: Part) -> &'static [u8] {
+        match part {
+            Part::One => &[1],
+            Part::Two => &[2],
+        }
+    }
+}
+
+pub fn cubes_1(games: &[Game]) -> Result<Answer> {
+    let sum: usize = games
+        .iter()
+        .filter_map(|game| {
+            let game_not_possible = game.rounds.iter().any(|round| {
+                round
+                    .iter()
+                    .any(|&(count, color)| MAP[color.as_str()] < count)
+            });
             if game_not_possible {
                 None
             } else {
-                Some(i + 1)
+                Some(game.id)
             }
         })
-        .map(|x| {
+        .inspect(|&x| {
             debug!("{}", x);
-            x
         })
         .sum();
-    info!("{}: sum is {}", name, sum);
-    Ok(())
+    Ok(sum.into())
 }
 
-fn cubes_2(name: &str, f: &str) -> Result<()> {
-    let sum: usize = f
-        .lines()
-        .enumerate()
-        .map(|(i, game)| {
+pub fn cubes_2(games: &[Game]) -> Result<Answer> {
+    let sum: usize = games
+        .iter()
+        .map(|game| {
             let mut red_heap = BinaryHeap::new();
             let mut green_heap = BinaryHeap::new();
             let mut blue_heap = BinaryHeap::new();
-            game.trim_start_matches(&format!("Game {}:", i + 1))
-                .split(';')
-                .for_each(|round| {
-                    round.split(',').for_each(|cube| {
-                        let entry = cube.trim().split(' ').collect::<Vec<_>>();
-                        let (count, color) = (
-                            entry[0]
-                                .parse::<usize>()
-                                .context(format!("unwrapping digit for {}", entry[0]))
-                                .unwrap(),
-                            entry[1],
-                        );
-                        match color {
-                            "red" => {
-                                red_heap.push(count);
-                            }
-                            "green" => {
-                                green_heap.push(count);
-                            }
-                            "blue" => {
-                                blue_heap.push(count);
-                            }
-                            _ => {
-                                error!("unknown color {}", color);
-                            }
+            game.rounds.iter().for_each(|round| {
+                round
+                    .iter()
+                    .for_each(|&(count, color)| match color.as_str() {
+                        "red" => {
+                            red_heap.push(count);
+                        }
+                        "green" => {
+                            green_heap.push(count);
+                        }
+                        "blue" => {
+                            blue_heap.push(count);
+                        }
+                        _ => {
+                            error!("unknown color {}", color);
                         }
                     })
-                });
-            red_heap.pop().unwrap() * green_heap.pop().unwrap() * blue_heap.pop().unwrap()
+            });
+            red_heap.pop().unwrap_or(0)
+                * green_heap.pop().unwrap_or(0)
+                * blue_heap.pop().unwrap_or(0)
         })
-        .map(|x| {
+        .inspect(|&x| {
             debug!("{}", x);
-            x
         })
         .sum();
-    info!("{}: sum is {}", name, sum);
-    Ok(())
+    Ok(sum.into())
 }
@@ -1,6 +1,8 @@
-use anyhow::{Result};
+use aoc_core::grid::Grid;
+use aoc_core::{register_day, Answer, Day, ParseError};
+use eyre::Result;
 
-use log::{debug, info};
+use log::debug;
 
 use std::collections::HashSet;
 
@@ -10,8 +12,8 @@ struct Part {
     location: (usize, usize),
 }
 
-struct Schematic {
-    cells: Vec<Vec<char>>,
+pub struct Schematic {
+    cells: Grid<char>,
     parts: Vec<Part>,
     gears: Vec<(usize, usize)>,
 }
@@ -53,9 +55,9 @@ fn to_gears(y: usize, line: &mut str) -> Vec<(usize, usize)> {
 }
 
 impl Schematic {
-    fn new(s: &str) -> Self {
+    pub fn new(s: &str) -> Result<Self, ParseError> {
+        let cells = Grid::parse(s)?;
         let parts = s
-            .clone()
             .lines()
             .enumerate()
             .flat_map(|(y, l)| {
@@ -65,17 +67,16 @@ impl Schematic {
             .collect();
 
         let gears = s
-            .clone()
             .lines()
             .enumerate()
             .flat_map(|(y, l)| to_gears(y, &mut l.to_owned()).into_iter())
             .collect();
         debug!("gears: {:#?}", gears);
-        Self {
-            cells: s.lines().map(|l| l.chars().collect()).collect(),
+        Ok(Self {
+            cells,
             parts,
             gears,
-        }
+        })
     }
 
     fn adjacent_parts_gear_ratio(&self, y: usize, x: usize) -> Option<usize> {
@@ -104,65 +105,51 @@ impl Schematic {
             None
         }
     }
-    fn is_adjacent_to_symbol(&self, x: isize, y: isize) -> bool {
-        (-1..=1).any(|dy| {
-            (-1..=1).any(|dx| {
-                if (dx == 0 && dy == 0)
-                    || x + dx < 0
-                    || x + dx >= self.cells[0].len() as isize
-                    || y + dy < 0
-                    || y + dy >= self.cells.len() as isize
-                {
-                    false
-                } else if self.cells[(y + dy) as usize][(x + dx) as usize] != '.'
-                    && !self.cells[(y + dy) as usize][(x + dx) as usize].is_ascii_digit()
-                {
-                    // debug!(
-                    //     "is_adjacent_to_symbol: {} at ({}, {})",
-                    //     self.cells[y as usize][x as usize], y, x
-                    // );
-                    true
-                } else {
-                    false
-                }
-            })
+    fn is_adjacent_to_symbol(&self, x: usize, y: usize) -> bool {
+        self.cells.neighbors8((x, y)).any(|point| {
+            let cell = self.cells[point];
+            // debug!("is_adjacent_to_symbol: {} at {:?}", cell, point);
+            cell != '.' && !cell.is_ascii_digit()
         })
     }
 }
 
-pub fn run() -> Result<()> {
-    engine_parts_1(
-        "sample",
-        &std::fs::read_to_string("src/sampledata/3.sample").unwrap(),
-    )?;
-    engine_parts_1(
-        "part 1",
-        &std::fs::read_to_string("src/sampledata/3.1").unwrap(),
-    )?;
-    engine_parts_2(
-        "sample",
-        &std::fs::read_to_string("src/sampledata/3.sample2").unwrap(),
-    )?;
-    engine_parts_2(
-        "part 2",
-        &std::fs::read_to_string("src/sampledata/3.1").unwrap(),
-    )?;
-    Ok(())
+pub struct EngineParts;
+
+register_day!(2023, 3, EngineParts);
+
+impl Day for EngineParts {
+    type Input<'a> = Schematic;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(Schematic::new(input)?)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        engine_parts_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        engine_parts_2(input)
+    }
+
+    fn samples(&self, part: aoc_core::Part) -> &'static [u8] {
+        match part {
+            aoc_core::Part::One => &[1],
+            aoc_core::Part::Two => &[2],
+        }
+    }
 }
 
-fn engine_parts_1(name: &str, f: &str) -> Result<()> {
-    let schematic = Schematic::new(f);
+pub fn engine_parts_1(schematic: &Schematic) -> Result<Answer> {
     let sum: usize = schematic
         .parts
         .iter()
         .filter_map(|part| {
             // debug!("checking {:?} with {}", part, part.number.len());
-            if (0..part.number.len()).any(|i| {
-                schematic.is_adjacent_to_symbol(
-                    part.location.1 as isize + i as isize,
-                    part.location.0 as isize,
-                )
-            }) {
+            if (0..part.number.len())
+                .any(|i| schematic.is_adjacent_to_symbol(part.location.1 + i, part.location.0))
+            {
                 // debug!("Adding {}", part.number);
                 Some(part.number.parse::<usize>().unwrap())
             } else {
@@ -170,12 +157,10 @@ fn engine_parts_1(name: &str, f: &str) -> Result<()> {
             }
         })
         .sum();
-    info!("{}: sum is {}", name, sum);
-    Ok(())
+    Ok(sum.into())
 }
 
-fn engine_parts_2(name: &str, f: &str) -> Result<()> {
-    let schematic = Schematic::new(f);
+pub fn engine_parts_2(schematic: &Schematic) -> Result<Answer> {
     let sum: usize = schematic
         .gears
         .iter()
@@ -185,6 +170,5 @@ fn engine_parts_2(name: &str, f: &str) -> Result<()> {
             ratio
         })
         .sum();
-    info!("{}: sum is {}", name, sum);
-    Ok(())
+    Ok(sum.into())
 }
@@ -0,0 +1,21 @@
+//! Where this year's inputs and solutions live. Shared with the build
+//! script, which generates a test for each sample from it.
+
+use aoc_core::{Layout, Source};
+
+/// Inputs live in `src/sampledata` as `N.1`, with samples as `N.sample`,
+/// `N.sample2` and so on.
+pub const LAYOUT: Layout = Layout {
+    year: 2023,
+    dir: concat!(env!("CARGO_MANIFEST_DIR"), "/src/sampledata"),
+    input: |day| format!("{}.1", day),
+    sample: |day, n| match n {
+        1 => format!("{}.sample", day),
+        _ => format!("{}.sample{}", day, n),
+    },
+    source: Source {
+        dir: concat!(env!("CARGO_MANIFEST_DIR"), "/src"),
+        module: |day| format!("day{}.rs", day),
+        declarations: "lib.rs",
+    },
+};
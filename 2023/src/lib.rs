@@ -0,0 +1,15 @@
+//! Solutions to Advent of Code 2023, one module per puzzle.
+
+pub mod cubes;
+pub mod engine_parts;
+mod layout;
+pub mod scratchcards;
+pub mod seeds;
+pub mod trebuchet;
+
+pub use layout::LAYOUT;
+
+#[cfg(test)]
+mod sample_tests {
+    include!(concat!(env!("OUT_DIR"), "/sample_tests.rs"));
+}
@@ -1,51 +1,72 @@
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day, ParseError};
 
-use anyhow::Result;
+use eyre::Result;
 
-use log::{debug, info};
-use regex::Regex;
+use log::debug;
 use std::collections::HashMap;
 use std::collections::HashSet;
 
-pub fn run() -> Result<()> {
-    scratchcards_1(
-        "sample",
-        &std::fs::read_to_string("src/sampledata/4.sample").unwrap(),
-    );
-    scratchcards_1(
-        "part 1",
-        &std::fs::read_to_string("src/sampledata/4.1").unwrap(),
-    );
-    scratchcards_2(
-        "sample 2",
-        &std::fs::read_to_string("src/sampledata/4.sample").unwrap(),
-    );
-    scratchcards_2(
-        "part 2",
-        &std::fs::read_to_string("src/sampledata/4.1").unwrap(),
-    );
-    Ok(())
+pub struct Card {
+    pub id: usize,
+    pub winning: HashSet<usize>,
+    pub numbers: HashSet<usize>,
 }
 
-fn scratchcards_1(name: &str, f: &str) -> Result<()> {
-    let sum: usize = f.lines().map(get_points_from_card).sum();
-    info!("{}: sum is {}", name, sum);
-    Ok(())
+/// Parse lines like "Card 1: 41 48 83 | 83 86  6".
+pub fn parse_cards(input: &str) -> Result<Vec<Card>, ParseError> {
+    let numbers = |list: &str| {
+        list.split_whitespace()
+            .map(|n| number(input, n))
+            .collect::<Result<HashSet<_>, _>>()
+    };
+    input
+        .lines()
+        .map(|line| {
+            let (id, rest) = line
+                .strip_prefix("Card")
+                .and_then(|rest| rest.split_once(':'))
+                .ok_or_else(|| ParseError::at(input, line, "a line like \"Card 1: 41 | 83\""))?;
+            let (winning, have) = rest
+                .split_once('|')
+                .ok_or_else(|| ParseError::after(input, rest, "a '|'"))?;
+            Ok(Card {
+                id: number(input, id.trim())?,
+                winning: numbers(winning)?,
+                numbers: numbers(have)?,
+            })
+        })
+        .collect()
 }
 
-fn get_points_from_card(card: &str) -> usize {
-    debug!("card: {}", card.clone().split(':').next().unwrap());
-    let card_vals = Regex::new("Card [0-9]+:").unwrap().replace_all(card, "");
-    let parts = card_vals
-        .split('|')
-        .map(str::trim)
-        .map(|s| {
-            s.split(' ')
-                .filter(|s| !s.is_empty())
-                .collect::<HashSet<_>>()
-        })
-        .collect::<Vec<_>>();
-    // debug!("parts: {:?}", parts);
-    let winning_numbers: Vec<_> = parts[0].intersection(&parts[1]).collect();
+pub struct Scratchcards;
+
+register_day!(2023, 4, Scratchcards);
+
+impl Day for Scratchcards {
+    type Input<'a> = Vec<Card>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(parse_cards(input)?)
+    }
+
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        scratchcards_1(input)
+    }
+
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        scratchcards_2(input)
+    }
+}
+
+pub fn scratchcards_1(cards: &[Card]) -> Result<Answer> {
+    let sum: usize = cards.iter().map(get_points_from_card).sum();
+    Ok(sum.into())
+}
+
+fn get_points_from_card(card: &Card) -> usize {
+    debug!("card: {}", card.id);
+    let winning_numbers: Vec<_> = card.winning.intersection(&card.numbers).collect();
     // debug!("winning numbers: {:?}", winning_numbers);
     let winning_number_count = winning_numbers.len();
     if winning_number_count == 0 {
@@ -61,20 +82,9 @@ fn get_points_from_card(card: &str) -> usize {
     }
 }
 
-fn get_points_from_card_2(card: &str) -> usize {
-    debug!("card: {}", card.clone().split(':').next().unwrap());
-    let card_vals = Regex::new("Card [0-9]+:").unwrap().replace_all(card, "");
-    let parts = card_vals
-        .split('|')
-        .map(str::trim)
-        .map(|s| {
-            s.split(' ')
-                .filter(|s| !s.is_empty())
-                .collect::<HashSet<_>>()
-        })
-        .collect::<Vec<_>>();
-    // debug!("parts: {:?}", parts);
-    let winning_numbers: Vec<_> = parts[0].intersection(&parts[1]).collect();
+fn get_points_from_card_2(card: &Card) -> usize {
+    debug!("card: {}", card.id);
+    let winning_numbers: Vec<_> = card.winning.intersection(&card.numbers).collect();
     // debug!("winning numbers: {:?}", winning_numbers);
     let winning_number_count = winning_numbers.len();
     if winning_number_count == 0 {
@@ -90,23 +100,24 @@ fn get_points_from_card_2(card: &str) -> usize {
     }
 }
 
-fn scratchcards_2(name: &str, f: &str) -> Result<()> {
+pub fn scratchcards_2(cards: &[Card]) -> Result<Answer> {
     // create a mapping to track count by card number
     let mut card_count_map = HashMap::new();
-    let original_card_count = f.lines().count();
+    let original_card_count = cards.len();
     // initialize the map
     for i in 0..original_card_count {
         card_count_map.insert(i, 1);
     }
     let final_sum: usize = original_card_count
-        + f.lines()
+        + cards
+            .iter()
             .enumerate()
             .map(|(i, card)| {
                 let count = *card_count_map.get(&i).unwrap();
                 let card_sum = get_points_from_card_2(card);
                 // for each of the next card_sum cards, increment the count
                 for x in i + 1..=i + card_sum {
-                    if x >= f.lines().count() {
+                    if x >= original_card_count {
                         continue;
                     }
                     *card_count_map.get_mut(&x).unwrap() += count;
@@ -115,7 +126,5 @@ fn scratchcards_2(name: &str, f: &str) -> Result<()> {
                 std::cmp::min(card_sum, original_card_count) * count
             })
             .sum::<usize>();
-    info!("{}: sum is {}", name, final_sum);
-
-    Ok(())
+    Ok(final_sum.into())
 }
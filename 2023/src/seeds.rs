@@ -1,341 +1,547 @@
-use anyhow::Result;
-use lazy_static::lazy_static;
-use log::{debug, info};
-use rayon::prelude::*;
-use regex::Regex;
-use std::cmp::Reverse;
-use std::collections::hash_map::DefaultHasher;
-use std::collections::BinaryHeap;
+use aoc_core::day::no_such_option;
+use aoc_core::interval::IntervalSet;
+use aoc_core::parse::number;
+use aoc_core::{register_day, Answer, Day, ParseError, Symbol};
+use eyre::{eyre, Result};
+use log::{debug, info, warn};
 use std::collections::HashMap;
-use std::collections::HashSet;
-use std::hash::{Hash, Hasher};
-use std::sync::Arc;
-use std::sync::Mutex;
-use std::time::Instant;
-
-lazy_static! {
-    static ref LOCATION_HASH: u64 = calculate_hash("location");
-}
+use std::fmt;
 
 /// (dest range, source range)
-type RangeMap = (std::ops::Range<usize>, std::ops::Range<usize>);
+pub type RangeMap = (std::ops::Range<usize>, std::ops::Range<usize>);
 
 #[derive(Debug, Clone)]
 struct SeedMapping {
-    source: String,
-    destination: String,
+    source: Symbol,
+    destination: Symbol,
     ranges: Vec<RangeMap>,
 }
 
 impl SeedMapping {
-    fn new(mapping_raw: &str) -> Self {
+    /// Parse a map like "seed-to-soil map:" and its ranges from
+    /// `mapping_raw`, a section of `input`.
+    fn new(input: &str, mapping_raw: &str) -> Result<Self, ParseError> {
         let mut parts = mapping_raw.split('\n');
-        let source_dest = parts
-            .next()
-            .expect("no parts")
-            .split(' ')
-            .next()
-            .expect("no source")
-            .split('-')
-            .collect::<Vec<_>>();
-        let (source, dest) = (source_dest[0], source_dest[2]);
+        let header = parts.next().unwrap_or_default();
+        let (source, dest) = header
+            .strip_suffix(" map:")
+            .and_then(|names| names.split_once("-to-"))
+            .ok_or_else(|| ParseError::at(input, header, "a header like \"seed-to-soil map:\""))?;
 
         let mut ranges: Vec<RangeMap> = parts
             .filter(|s| !s.is_empty())
             .map(|s| {
                 let range_parts = s.split(' ').collect::<Vec<_>>();
+                if range_parts.len() != 3 {
+                    return Err(ParseError::at(input, s, "a destination, source and length"));
+                }
                 let (dest_i, source_i, length): (usize, usize, usize) = (
-                    range_parts[0].parse().expect("parsing dest"),
-                    range_parts[1].parse().expect("parsing source"),
-                    range_parts[2].parse().expect("parsing length"),
+                    number(input, range_parts[0])?,
+                    number(input, range_parts[1])?,
+                    number(input, range_parts[2])?,
                 );
-                (dest_i..dest_i + length, source_i..source_i + length)
+                Ok((dest_i..dest_i + length, source_i..source_i + length))
             })
-            .collect();
-        ranges.sort_by(|a, b| a.1.start.cmp(&b.1.start));
-        Self {
-            source: source.to_owned(),
-            destination: dest.to_owned(),
+            .collect::<Result<_, _>>()?;
+        ranges.sort_by_key(|a| a.1.start);
+        Ok(Self {
+            source: Symbol::new(source),
+            destination: Symbol::new(dest),
             ranges,
+        })
+    }
+
+    fn name(&self) -> String {
+        format!("{}-to-{}", self.source, self.destination)
+    }
+
+    /// Where `source_num` goes, and the range that sent it there if any.
+    /// Where ranges overlap the first one wins.
+    fn get(&self, source_num: usize) -> (usize, Option<&RangeMap>) {
+        let matched = self.ranges.iter().find(|(_, s)| s.contains(&source_num));
+        match matched {
+            Some(range_map @ (d, s)) => (d.start + (source_num - s.start), Some(range_map)),
+            None => (source_num, None),
         }
     }
 
-    fn get(&self, source_num: &str) -> String {
-        // check if the source is in the source ranges
-        let range_result = self.ranges.iter().find_map(|(d, s)| {
-            let src = source_num.parse::<usize>().unwrap();
-            if s.contains(&src) {
-                d.clone().nth(src - s.start)
-            } else {
-                None
+    /// The map as a function. Where ranges overlap the first one wins.
+    fn piecewise(&self) -> Piecewise {
+        let mut covered = IntervalSet::new();
+        let mut pieces = vec![];
+        for (dest, source) in &self.ranges {
+            let source_range = IntervalSet::from(source.start as isize..source.end as isize);
+            let fresh = source_range.difference(&covered);
+            pieces.extend(fresh.ranges().iter().map(|r| RangeDelta {
+                source_range: r.clone(),
+                delta: source.start as isize - dest.start as isize,
+            }));
+            covered = covered.union(&source_range);
+        }
+        Piecewise::new(pieces)
+    }
+}
+
+/// One map's part in getting a seed to its location.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub map: String,
+    pub source: usize,
+    pub destination: usize,
+    /// The (dest range, source range) that matched, if any
+    pub matched: Option<RangeMap>,
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {} -> {}", self.map, self.source, self.destination)?;
+        match &self.matched {
+            Some((d, s)) => write!(f, " (matched {:?} -> {:?})", s, d),
+            None => write!(f, " (no match)"),
+        }
+    }
+}
+
+/// Something suspicious about an almanac's maps.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Problem {
+    /// Two source ranges of a map overlap, so the first one wins
+    Overlap {
+        map: String,
+        first: std::ops::Range<usize>,
+        second: std::ops::Range<usize>,
+    },
+    /// Numbers between two source ranges of a map, which map to themselves
+    Gap {
+        map: String,
+        range: std::ops::Range<usize>,
+    },
+    /// The chain from seed reaches a category that nothing maps from
+    Missing { category: Symbol },
+    /// The chain from seed comes back to a category it already went through
+    Cycle { path: Vec<Symbol> },
+}
+
+impl Problem {
+    /// Whether seeds can't get to a location at all.
+    fn is_fatal(&self) -> bool {
+        matches!(self, Problem::Missing { .. } | Problem::Cycle { .. })
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Problem::Overlap { map, first, second } => {
+                write!(
+                    f,
+                    "{}: source ranges {:?} and {:?} overlap",
+                    map, first, second
+                )
+            }
+            Problem::Gap { map, range } => {
+                write!(f, "{}: {:?} falls between source ranges", map, range)
+            }
+            Problem::Missing { category } => write!(f, "nothing maps from {}", category),
+            Problem::Cycle { path } => {
+                let path: Vec<_> = path.iter().map(|c| c.as_str()).collect();
+                write!(f, "the maps go round in a cycle: {}", path.join(" -> "))
             }
-        });
-        if let Some(res) = range_result {
-            debug!("mapping: {} -> {}", source_num, res);
-            res.to_string()
-        } else {
-            debug!("mapping: {} -> {} (no match)", source_num, source_num);
-            source_num.to_owned()
         }
     }
 }
 
-struct Almanac {
+#[derive(Debug, Clone)]
+pub struct Almanac {
     /// The seeds that are part of the almanac
-    seeds: Vec<String>,
-    /// The mappings of various attributes
-    /// key: source attribute
-    /// value: HashMap<destination attribute, ranges??>
-    mappings: HashMap<String, SeedMapping>,
-    map_cache: HashMap<String, String>,
+    seeds: Vec<usize>,
+    /// Each map, keyed by the category it maps from
+    mappings: HashMap<Symbol, SeedMapping>,
 }
 
 impl Almanac {
-    fn new(raw: &str) -> Self {
-        let seeds = raw
-            .lines()
-            .next()
-            .expect("no lines")
-            .split(':')
-            .map(str::trim)
-            .collect::<Vec<_>>()[1]
-            .split(' ')
-            .map(str::to_string)
-            .collect();
-        let mappings: HashMap<_, _> =
-            raw.split("\n\n")
-                .skip(1)
-                .fold(HashMap::new(), |mut acc, mapping_raw| {
-                    let mapping = SeedMapping::new(mapping_raw);
-                    acc.insert(mapping.source.to_owned(), mapping);
-                    acc
-                });
+    pub fn new(raw: &str) -> Result<Self, ParseError> {
+        let almanac = Self::parse(raw)?;
+        // every seed has to end up at a location
+        match almanac.problems().into_iter().find(Problem::is_fatal) {
+            Some(Problem::Missing { category }) => {
+                Err(ParseError::end(raw, format!("a map from {}", category)))
+            }
+            Some(problem) => Err(ParseError::end(
+                raw,
+                format!("maps that reach location, but {}", problem),
+            )),
+            None => Ok(almanac),
+        }
+    }
+
+    /// Parse the almanac without checking that its maps make sense.
+    fn parse(raw: &str) -> Result<Self, ParseError> {
+        let first_line = raw.lines().next().unwrap_or_default();
+        let seeds = first_line
+            .strip_prefix("seeds:")
+            .ok_or_else(|| ParseError::at(raw, first_line, "a \"seeds:\" line"))?
+            .split_whitespace()
+            .map(|seed| number(raw, seed))
+            .collect::<Result<_, _>>()?;
+        let mut mappings = HashMap::new();
+        for mapping_raw in raw.split("\n\n").skip(1) {
+            let mapping = SeedMapping::new(raw, mapping_raw)?;
+            mappings.insert(mapping.source, mapping);
+        }
         debug!("Mappings: {:#?}", mappings);
-        Self {
-            seeds,
-            mappings,
-            map_cache: HashMap::new(),
+        Ok(Self { seeds, mappings })
+    }
+
+    /// The categories from seed on, as far as the maps go.
+    fn chain(&self) -> (Vec<Symbol>, Option<Problem>) {
+        let location = Symbol::new("location");
+        let mut category = Symbol::new("seed");
+        let mut path = vec![category];
+        while category != location {
+            let Some(mapping) = self.mappings.get(&category) else {
+                return (path, Some(Problem::Missing { category }));
+            };
+            category = mapping.destination;
+            let seen = path.contains(&category);
+            path.push(category);
+            if seen {
+                let cycle = path.clone();
+                return (path, Some(Problem::Cycle { path: cycle }));
+            }
+        }
+        (path, None)
+    }
+
+    /// Everything suspicious about the maps: overlapping source ranges and
+    /// gaps between them in each map, and breaks in the chain of categories
+    /// from seed to location.
+    pub fn problems(&self) -> Vec<Problem> {
+        let (path, chain_problem) = self.chain();
+        let mut maps: Vec<_> = self.mappings.values().collect();
+        // the maps on the way to location first, in order
+        maps.sort_by_key(|m| {
+            let on_path = path.iter().position(|&c| c == m.source);
+            (on_path.unwrap_or(usize::MAX), m.source.as_str())
+        });
+
+        let mut problems = vec![];
+        for mapping in maps {
+            // the ranges are sorted by source start
+            let mut furthest: Option<&std::ops::Range<usize>> = None;
+            for (_, source) in &mapping.ranges {
+                match furthest {
+                    Some(prev) if source.start < prev.end => problems.push(Problem::Overlap {
+                        map: mapping.name(),
+                        first: prev.clone(),
+                        second: source.clone(),
+                    }),
+                    Some(prev) if source.start > prev.end => problems.push(Problem::Gap {
+                        map: mapping.name(),
+                        range: prev.end..source.start,
+                    }),
+                    _ => {}
+                }
+                if furthest.is_none_or(|prev| source.end > prev.end) {
+                    furthest = Some(source);
+                }
+            }
         }
+        problems.extend(chain_problem);
+        problems
     }
 
-    fn get_mappings(&mut self, source_name: &str, num: &str) -> usize {
-        // debug!("Getting mapping from {}: {}", source_name, num);
-        let seed_mapping = &self.mappings[source_name];
-        // early return if cached
-        let map_cache_index = format!("{}:{}", source_name, num);
-        let mapping_result = if let Some(mapping) = self.map_cache.get(&map_cache_index) {
-            debug!("!!! cache hit on {}", num);
-            return mapping.parse().expect("parsing seed mapping result");
-        } else {
-            seed_mapping.get(num)
-        };
-        if seed_mapping.destination == "location" {
-            self.map_cache
-                .insert(map_cache_index, mapping_result.clone());
-            mapping_result.parse().expect("parsing seed mapping result")
-        } else {
-            let dest = seed_mapping.destination.clone();
-            self.get_mappings(&dest, &mapping_result)
+    /// Follow `seed` through every map to its location.
+    pub fn trace(&self, seed: usize) -> Vec<Step> {
+        let mut steps = vec![];
+        let location = Symbol::new("location");
+        let mut category = Symbol::new("seed");
+        let mut num = seed;
+        while category != location {
+            let Some(mapping) = self.mappings.get(&category) else {
+                break;
+            };
+            let (destination, matched) = mapping.get(num);
+            steps.push(Step {
+                map: mapping.name(),
+                source: num,
+                destination,
+                matched: matched.cloned(),
+            });
+            num = destination;
+            category = mapping.destination;
         }
+        steps
     }
 
-    fn locations(&mut self) -> Vec<usize> {
-        let mut res = vec![];
-        let seeds = self.seeds.clone();
-        for seed in seeds {
-            debug!("Getting location for {}", seed);
-            res.push(self.get_mappings("seed", &seed));
+    /// The seeds line read as pairs of a start and a length.
+    pub fn seed_ranges(&self) -> Result<IntervalSet> {
+        if !self.seeds.len().is_multiple_of(2) {
+            return Err(eyre!("seeds should come in start and length pairs"));
         }
-        res
+        let seeds: IntervalSet = self
+            .seeds
+            // create ranges from each pairing
+            .chunks(2)
+            .map(|chunk| chunk[0] as isize..(chunk[0] + chunk[1]) as isize)
+            .collect();
+        info!("{:?} seeds in {} ranges", seeds.len(), seeds.ranges().len());
+        Ok(seeds)
+    }
+
+    /// Every map from seed to location composed into one function.
+    pub fn seed_to_location(&self) -> Piecewise {
+        let location = Symbol::new("location");
+        let mut category = Symbol::new("seed");
+        let mut function = Piecewise::default();
+        while category != location {
+            let mapping = &self.mappings[&category];
+            function = function.then(&mapping.piecewise());
+            category = mapping.destination;
+        }
+        info!("seed to location has {} pieces", function.pieces.len());
+        function
     }
 }
 
 #[derive(Debug, Clone)]
 struct RangeDelta {
-    dest_range: std::ops::Range<isize>,
     source_range: std::ops::Range<isize>,
     delta: isize,
 }
 
-#[derive(Debug, Clone)]
-struct SeedMappingv2 {
-    source: u64,
-    destination: u64,
-    ranges: Vec<RangeDelta>,
-    removed_vals: Vec<usize>,
-}
-
-fn calculate_hash<T: Hash + ?Sized>(t: &T) -> u64 {
-    let mut s = DefaultHasher::new();
-    t.hash(&mut s);
-    s.finish()
+/// A function made of pieces that each shift a range of numbers by the
+/// same amount. Numbers outside every piece map to themselves.
+#[derive(Debug, Clone, Default)]
+pub struct Piecewise {
+    /// Sorted by start, never overlapping, and never with a delta of 0
+    pieces: Vec<RangeDelta>,
 }
 
-impl SeedMappingv2 {
-    fn new(mapping_raw: &str) -> Self {
-        let mut parts = mapping_raw.split('\n');
-        let source_dest = parts
-            .next()
-            .expect("no parts")
-            .split(' ')
-            .next()
-            .expect("no source")
-            .split('-')
-            .collect::<Vec<_>>();
-        let (source, dest) = (source_dest[0], source_dest[2]);
-
-        let mut ranges: Vec<_> = parts
-            .filter(|s| !s.is_empty())
-            .map(|s| {
-                let range_parts = s.split(' ').collect::<Vec<_>>();
-                let (dest_i, source_i, length): (isize, isize, isize) = (
-                    range_parts[0].parse().expect("parsing dest"),
-                    range_parts[1].parse().expect("parsing source"),
-                    range_parts[2].parse().expect("parsing length"),
-                );
-                RangeDelta {
-                    dest_range: dest_i..dest_i + length,
-                    source_range: source_i..source_i + length,
-                    delta: source_i as isize - dest_i as isize,
+impl Piecewise {
+    fn new(mut pieces: Vec<RangeDelta>) -> Self {
+        pieces.retain(|piece| piece.delta != 0 && !piece.source_range.is_empty());
+        pieces.sort_by_key(|piece| piece.source_range.start);
+        let mut merged: Vec<RangeDelta> = Vec::with_capacity(pieces.len());
+        for piece in pieces {
+            match merged.last_mut() {
+                Some(last)
+                    if last.source_range.end == piece.source_range.start
+                        && last.delta == piece.delta =>
+                {
+                    last.source_range.end = piece.source_range.end
                 }
-            })
+                _ => merged.push(piece),
+            }
+        }
+        Self { pieces: merged }
+    }
+
+    /// The numbers that some piece covers.
+    fn domain(&self) -> IntervalSet {
+        self.pieces
+            .iter()
+            .map(|piece| piece.source_range.clone())
+            .collect()
+    }
+
+    pub fn get(&self, source_num: isize) -> isize {
+        let after = self
+            .pieces
+            .partition_point(|piece| piece.source_range.start <= source_num);
+        match after.checked_sub(1).map(|i| &self.pieces[i]) {
+            Some(piece) if piece.source_range.contains(&source_num) => source_num - piece.delta,
+            _ => source_num,
+        }
+    }
+
+    /// Everything that `sources` maps to.
+    pub fn image(&self, sources: &IntervalSet) -> IntervalSet {
+        let mut dests = IntervalSet::new();
+        for piece in &self.pieces {
+            let hit = sources.intersection(&piece.source_range.clone().into());
+            dests = dests.union(&hit.shift(-piece.delta));
+        }
+        dests.union(&sources.difference(&self.domain()))
+    }
+
+    /// Every number that maps to `dest_num`, smallest first.
+    pub fn inverse(&self, dest_num: isize) -> Vec<isize> {
+        let mut sources: Vec<_> = self
+            .pieces
+            .iter()
+            .map(|piece| dest_num + piece.delta)
+            .filter(|&source_num| self.get(source_num) == dest_num)
             .collect();
-        Self {
-            removed_vals: vec![],
-            source: calculate_hash(source),
-            destination: calculate_hash(dest),
-            ranges,
+        if self.get(dest_num) == dest_num {
+            sources.push(dest_num);
         }
+        sources.sort_unstable();
+        sources.dedup();
+        sources
     }
 
-    fn get(&self, source_num: isize) -> isize {
-        // check if the source is in the source ranges
-        let mut dest = source_num;
-        for range_delta in &self.ranges {
-            if range_delta.source_range.contains(&source_num) {
-                dest = source_num - range_delta.delta;
-                break;
+    /// The function that applies this one and then `next`.
+    fn then(&self, next: &Piecewise) -> Piecewise {
+        let mut pieces = vec![];
+        for piece in &self.pieces {
+            // split what this piece maps to wherever the next function does
+            let image = IntervalSet::from(piece.source_range.clone()).shift(-piece.delta);
+            for next_piece in &next.pieces {
+                let hit = image.intersection(&next_piece.source_range.clone().into());
+                pieces.extend(hit.shift(piece.delta).ranges().iter().map(|r| RangeDelta {
+                    source_range: r.clone(),
+                    delta: piece.delta + next_piece.delta,
+                }));
             }
+            let missed = image.difference(&next.domain()).shift(piece.delta);
+            pieces.extend(missed.ranges().iter().map(|r| RangeDelta {
+                source_range: r.clone(),
+                delta: piece.delta,
+            }));
         }
-        dest
+        // numbers this function leaves alone only move in the next one
+        let untouched = next.domain().difference(&self.domain());
+        for next_piece in &next.pieces {
+            let hit = untouched.intersection(&next_piece.source_range.clone().into());
+            pieces.extend(hit.ranges().iter().map(|r| RangeDelta {
+                source_range: r.clone(),
+                delta: next_piece.delta,
+            }));
+        }
+        Piecewise::new(pieces)
     }
 }
 
-struct Almanacv2 {
-    /// The seeds that are part of the almanac
-    seeds: Vec<std::ops::Range<isize>>,
-    /// The mappings of various attributes
-    /// key: source attribute
-    /// value: HashMap<destination attribute, ranges??>
-    mappings: HashMap<u64, SeedMappingv2>,
-    map_cache: HashMap<String, String>,
+#[derive(Default)]
+pub struct Seeds {
+    /// A seed to follow through the maps while solving part 1
+    trace: Option<usize>,
 }
 
-impl Almanacv2 {
-    fn new(raw: &str) -> Self {
-        let seeds: Vec<std::ops::Range<isize>> = raw
-            .lines()
-            .next()
-            .expect("no lines")
-            .split(':')
-            .map(str::trim)
-            .collect::<Vec<_>>()[1]
-            // separate into values
-            .split(' ')
-            // parse to ints
-            .map(str::parse::<isize>)
-            // unwrap
-            .flatten()
-            // create ranges from each pairing
-            .collect::<Vec<_>>()
-            .chunks(2)
-            .map(|chunk| (chunk[0]..chunk[0] + chunk[1]))
-            .collect();
-        info!(
-            "{:?} seeds",
-            seeds.iter().fold(0, |acc, b| acc + b.end - b.start)
-        );
-        let mappings: HashMap<_, _> =
-            raw.split("\n\n")
-                .skip(1)
-                .fold(HashMap::new(), |mut acc, mapping_raw| {
-                    let mapping = SeedMappingv2::new(mapping_raw);
-                    acc.insert(mapping.source.to_owned(), mapping);
-                    acc
-                });
-        debug!("Mappings: {:#?}", mappings);
-        Self {
-            seeds,
-            mappings,
-            map_cache: HashMap::new(),
+register_day!(2023, 5, Seeds::default());
+
+impl Day for Seeds {
+    type Input<'a> = Almanac;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        let almanac = Almanac::new(input)?;
+        for problem in almanac.problems() {
+            // gaps are how most almanacs leave numbers alone
+            match problem {
+                Problem::Gap { .. } => debug!("{}", problem),
+                _ => warn!("{}", problem),
+            }
         }
+        Ok(almanac)
     }
 
-    fn get_mappings(&self, source_name: u64, num: isize, mut depth: usize) -> isize {
-        // debug!("Getting mapping from {}: {}", source_name, num);
-        // debug!("d: {}", depth);
-        let seed_mapping = &self.mappings[&source_name];
-        let mapping_result = seed_mapping.get(num);
-        if seed_mapping.destination == *LOCATION_HASH {
-            mapping_result
-        } else {
-            self.get_mappings(seed_mapping.destination, mapping_result, depth + 1)
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        if let Some(seed) = self.trace {
+            info!("tracing seed {}", seed);
+            for step in input.trace(seed) {
+                info!("  {}", step);
+            }
         }
+        seeds_1(input)
     }
 
-    fn min_location(&mut self) -> usize {
-        let mut min = std::isize::MAX;
-        for seed_range in self.seeds.clone() {
-            info!("starting range {:?}", seed_range);
-            let seed_hash = calculate_hash("seed");
-            for seed in seed_range {
-                let timer = Instant::now();
-                min = min.min(self.get_mappings(seed_hash, seed, 0));
-                debug!(
-                    "Got location for {} in {:?}",
-                    seed,
-                    timer.elapsed().as_nanos(),
-                );
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+        seeds_2(input)
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<()> {
+        match key {
+            "trace" => {
+                let seed = value
+                    .parse()
+                    .map_err(|_| eyre!("expected a seed number, found {:?}", value))?;
+                self.trace = Some(seed);
             }
-            // info!("finished range {:?}", seed_range);
+            _ => return Err(no_such_option(key)),
         }
-        min as usize
+        Ok(())
     }
 }
 
-pub fn run() -> Result<()> {
-    seeds_1(
-        "sample",
-        &std::fs::read_to_string("src/sampledata/5.sample").unwrap(),
-    )?;
-    seeds_1(
-        "part 1",
-        &std::fs::read_to_string("src/sampledata/5.1").unwrap(),
-    )?;
-    seeds_2(
-        "smaple 2",
-        &std::fs::read_to_string("src/sampledata/5.sample").unwrap(),
-    )?;
-    seeds_2(
-        "part 2",
-        &std::fs::read_to_string("src/sampledata/5.1").unwrap(),
-    )?;
-    Ok(())
+pub fn seeds_1(almanac: &Almanac) -> Result<Answer> {
+    let function = almanac.seed_to_location();
+    // find the location for each of the given seeds
+    let min_location = almanac
+        .seeds
+        .iter()
+        .map(|&seed| function.get(seed as isize))
+        .min()
+        .ok_or_else(|| eyre!("no seeds"))?;
+    debug!(
+        "seeds reaching {}: {:?}",
+        min_location,
+        function.inverse(min_location)
+    );
+    Ok((min_location as usize).into())
 }
 
-fn seeds_1(name: &str, f: &str) -> Result<()> {
-    let mut almanac = Almanac::new(f);
-    // find the location for each of the given seeds
-    let locations = almanac.locations();
-    let min_location = locations.iter().min();
-    info!("{}: min location is {:?}", name, min_location);
-    Ok(())
+pub fn seeds_2(almanac: &Almanac) -> Result<Answer> {
+    let seeds = almanac.seed_ranges()?;
+    // find the locations for every seed range at once
+    let function = almanac.seed_to_location();
+    let min_location = function
+        .image(&seeds)
+        .min()
+        .ok_or_else(|| eyre!("no seeds"))?;
+    debug!(
+        "seeds reaching {}: {:?}",
+        min_location,
+        function.inverse(min_location)
+    );
+    Ok((min_location as usize).into())
 }
 
-fn seeds_2(name: &str, f: &str) -> Result<()> {
-    let mut almanac = Almanacv2::new(f);
-    // find the location for each of the given seeds
-    let min_location = almanac.min_location();
-    info!("{}: min location is {:?}", name, min_location);
-    Ok(())
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ops::Range;
+
+    /// A function adding `by` to every number in each range.
+    fn shifts(pieces: &[(Range<isize>, isize)]) -> Piecewise {
+        Piecewise::new(
+            pieces
+                .iter()
+                .map(|(range, by)| RangeDelta {
+                    source_range: range.clone(),
+                    delta: -by,
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn then_splits_pieces_across_boundaries_and_gaps() {
+        let first = shifts(&[(0..10, 100), (20..30, -20)]);
+        let next = shifts(&[(5..25, 1), (105..115, 1000)]);
+        let both = first.then(&next);
+
+        // 0..5 lands in a gap of next, 5..10 in its second piece
+        assert_eq!(both.get(3), 103);
+        assert_eq!(both.get(7), 1107);
+        // 20..30 lands on 0..10, which next splits at 5
+        assert_eq!(both.get(22), 2);
+        assert_eq!(both.get(27), 8);
+        // first leaves 10..20 alone for next to move
+        assert_eq!(both.get(15), 16);
+        assert_eq!(both.get(40), 40);
+        for n in -10..130 {
+            assert_eq!(both.get(n), next.get(first.get(n)), "at {}", n);
+        }
+    }
+
+    #[test]
+    fn inverse_finds_every_preimage() {
+        let function = shifts(&[(0..10, 10), (20..30, -10)]);
+        assert_eq!(function.inverse(15), [5, 15, 25]);
+        assert_eq!(function.inverse(12), [2, 12, 22]);
+        // 5 moves away and neither neighbour lands on it
+        assert!(function.inverse(5).is_empty());
+        assert_eq!(function.inverse(40), [40]);
+    }
 }
@@ -1,7 +1,8 @@
-use anyhow::Context;
+use aoc_core::{register_day, Answer, Day, ParseError, Part};
+use eyre::Result;
 use lazy_static::lazy_static;
 
-use log::{debug, info};
+use log::debug;
 use std::collections::HashMap;
 
 lazy_static! {
@@ -21,33 +22,39 @@ lazy_static! {
     .collect();
 }
 
-pub fn run() -> anyhow::Result<()> {
-    trebuchet_1(
-        "sample",
-        &std::fs::read_to_string("src/sampledata/1.sample").unwrap(),
-    );
-    trebuchet_1(
-        "part 1",
-        &std::fs::read_to_string("src/sampledata/1.1").unwrap(),
-    );
-    trebuchet_2(
-        "sample",
-        &std::fs::read_to_string("src/sampledata/1.sample2").unwrap(),
-    );
-    trebuchet_2(
-        "part 2",
-        &std::fs::read_to_string("src/sampledata/1.1").unwrap(),
-    );
-    Ok(())
+pub struct Trebuchet;
+
+register_day!(2023, 1, Trebuchet);
+
+impl Day for Trebuchet {
+    type Input<'a> = &'a str;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(input)
+    }
+
+    fn part_1(&self, f: &Self::Input<'_>) -> Result<Answer> {
+        Ok(trebuchet_1(f)?.into())
+    }
+
+    fn part_2(&self, f: &Self::Input<'_>) -> Result<Answer> {
+        Ok(trebuchet_2(f)?.into())
+    }
+
+    fn samples(&self, part: Part) -> &'static [u8] {
+        match part {
+            Part::One => &[1],
+            Part::Two => &[2],
+        }
+    }
 }
 
-fn trebuchet_2(name: &str, f: &str) {
-    let res: i32 = f
-        .lines()
+pub fn trebuchet_2(f: &str) -> Result<i32, ParseError> {
+    f.lines()
         .map(|line| {
             let mut first_digit = '!';
-            for (i, ch) in line.chars().enumerate() {
-                let line_remainder = &line.clone()[i..];
+            for (i, ch) in line.char_indices() {
+                let line_remainder = &line[i..];
                 if ch.is_ascii_digit() {
                     first_digit = ch;
                     break;
@@ -65,9 +72,8 @@ fn trebuchet_2(name: &str, f: &str) {
 
             let mut last_digit = '!';
 
-            for (i, ch) in line.chars().rev().enumerate() {
-                let i = line.len() - 1 - i;
-                let line_remainder = &line.clone()[i..];
+            for (i, ch) in line.char_indices().rev() {
+                let line_remainder = &line[i..];
                 if ch.is_ascii_digit() {
                     last_digit = ch;
                     break;
@@ -82,34 +88,33 @@ fn trebuchet_2(name: &str, f: &str) {
                     }
                 }
             }
+            if first_digit == '!' {
+                return Err(ParseError::at(f, line, "a digit or a spelled-out digit"));
+            }
             let line_result = format!("{}{}", first_digit, last_digit)
                 .parse::<i32>()
-                .context(format!("{}: {} and {}", name, first_digit, last_digit))
-                .unwrap();
+                .expect("both are digits");
             debug!("{}: {} and {}", line_result, first_digit, last_digit);
-            line_result
+            Ok(line_result)
         })
-        .sum();
-
-    info!("{}: sum is {}", name, res);
+        .sum()
 }
 
-fn trebuchet_1(name: &str, f: &str) {
-    let res: i32 = f
-        .lines()
+pub fn trebuchet_1(f: &str) -> Result<i32, ParseError> {
+    f.lines()
         .map(|line| {
-            let digit_chars = line.chars().filter(|ch| ch.is_ascii_digit());
-            let line_num = vec![
-                digit_chars.clone().next().unwrap(),
-                digit_chars.last().unwrap(),
-            ]
-            .iter()
-            .collect::<String>()
-            .parse::<i32>()
-            .unwrap();
+            let mut digit_chars = line.chars().filter(|ch| ch.is_ascii_digit());
+            let (first, last) = match (digit_chars.clone().next(), digit_chars.next_back()) {
+                (Some(first), Some(last)) => (first, last),
+                _ => return Err(ParseError::at(f, line, "a line with a digit")),
+            };
+            let line_num = [first, last]
+                .iter()
+                .collect::<String>()
+                .parse::<i32>()
+                .expect("both are digits");
             debug!("{}", line_num);
-            line_num
+            Ok(line_num)
         })
-        .sum();
-    info!("{}: sum is {}", name, res);
+        .sum()
 }
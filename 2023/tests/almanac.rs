@@ -0,0 +1,43 @@
+use aoc_2023::seeds::{Almanac, Problem};
+
+const SAMPLE: &str = include_str!("../src/sampledata/5.sample");
+
+#[test]
+fn traces_a_seed() {
+    let almanac = Almanac::new(SAMPLE).unwrap();
+    let steps = almanac.trace(79);
+    let path: Vec<_> = steps.iter().map(|s| s.destination).collect();
+    assert_eq!(path, [81, 81, 81, 74, 78, 78, 82]);
+    assert_eq!(steps[0].matched, Some((52..100, 50..98)));
+    assert_eq!(steps[1].matched, None);
+    assert_eq!(almanac.seed_to_location().inverse(82), [79]);
+}
+
+#[test]
+fn finds_problems() {
+    let almanac =
+        Almanac::new("seeds: 1\n\nseed-to-location map:\n0 5 10\n0 10 2\n0 20 1\n").unwrap();
+    assert_eq!(
+        almanac.problems(),
+        [
+            Problem::Overlap {
+                map: "seed-to-location".to_owned(),
+                first: 5..15,
+                second: 10..12,
+            },
+            Problem::Gap {
+                map: "seed-to-location".to_owned(),
+                range: 15..20,
+            },
+        ]
+    );
+
+    let missing = Almanac::new("seeds: 1\n\nseed-to-soil map:\n0 5 10\n").unwrap_err();
+    assert_eq!(missing.expected, "a map from soil");
+    let cycle = Almanac::new("seeds: 1\n\nseed-to-soil map:\n\nsoil-to-seed map:\n").unwrap_err();
+    assert!(
+        cycle.expected.ends_with("seed -> soil -> seed"),
+        "{}",
+        cycle
+    );
+}
@@ -0,0 +1,330 @@
+use crate::data::DataDirs;
+use crate::day::Part;
+use crate::runner::Record;
+use eyre::{eyre, Result, WrapErr};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{read_to_string, write};
+use std::path::{Path, PathBuf};
+
+/// The known good answers for a year, kept next to its inputs so that
+/// refactors can be checked against them. On disk it's a table per day and
+/// part, keyed by input name:
+///
+/// ```toml
+/// [day1.part1]
+/// input = "877971"
+/// sample = "514579"
+/// ```
+#[derive(Debug)]
+pub struct Answers(PartTables);
+
+impl Answers {
+    pub const FILE_NAME: &'static str = "answers.toml";
+
+    /// Load the answers file from the first of `dirs` that has one. If none
+    /// do, the answers start out empty and will be saved to the highest
+    /// priority directory.
+    pub fn load(dirs: &DataDirs) -> Result<Self> {
+        PartTables::load(dirs, Self::FILE_NAME).map(Self)
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self> {
+        PartTables::from_file(path).map(Self)
+    }
+
+    /// Where the answers were loaded from, and will be saved to.
+    pub fn path(&self) -> &Path {
+        self.0.path()
+    }
+
+    /// The recorded answer for `part` of `day` against the named input.
+    pub fn get(&self, day: u8, part: Part, input: &str) -> Option<&str> {
+        self.0.get(day, part, input)
+    }
+
+    pub fn set(&mut self, day: u8, part: Part, input: &str, answer: String) {
+        self.0.set(day, part, input, answer)
+    }
+
+    /// Set the answer from every record that has one. Failed runs leave
+    /// whatever was recorded before.
+    pub fn record(&mut self, records: &[Record]) {
+        for r in records {
+            if let Ok(solved) = &r.outcome {
+                self.set(r.day, r.part, &r.input, solved.answer.to_string());
+            }
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        self.0.save()
+    }
+}
+
+/// The answers a puzzle description gives for one of its examples, kept
+/// beside the sample as `<sample>.expected`:
+///
+/// ```toml
+/// part1 = "142"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Expected {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    /// The sidecar for the sample at `sample`.
+    pub fn path(sample: &Path) -> PathBuf {
+        let mut path = sample.as_os_str().to_owned();
+        path.push(".expected");
+        PathBuf::from(path)
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let raw = read_to_string(path).wrap_err_with(|| format!("reading {}", path.display()))?;
+        toml::from_str(&raw).wrap_err_with(|| format!("parsing {}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        write(path, toml::to_string(self)?).wrap_err_with(|| format!("writing {}", path.display()))
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: String) {
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer),
+        }
+    }
+}
+
+/// A TOML file holding a table of strings for each day and part, under
+/// `[dayN.partM]` headers, after any numbers that belong to the whole
+/// file.
+#[derive(Debug)]
+pub(crate) struct PartTables {
+    path: PathBuf,
+    numbers: BTreeMap<String, u64>,
+    parts: BTreeMap<(u8, Part), BTreeMap<String, String>>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Entry {
+    Number(u64),
+    Day(BTreeMap<String, BTreeMap<String, String>>),
+}
+
+type Raw = BTreeMap<String, Entry>;
+
+impl PartTables {
+    /// Load `file_name` from the first of `dirs` that has it, or start out
+    /// empty to be saved in the highest priority directory.
+    pub fn load(dirs: &DataDirs, file_name: &str) -> Result<Self> {
+        match dirs.locate(file_name) {
+            Some(path) => Self::from_file(&path),
+            None => Ok(Self {
+                path: dirs.primary().join(file_name),
+                numbers: BTreeMap::new(),
+                parts: BTreeMap::new(),
+            }),
+        }
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let raw = read_to_string(path).wrap_err_with(|| format!("reading {}", path.display()))?;
+        let raw: Raw =
+            toml::from_str(&raw).wrap_err_with(|| format!("parsing {}", path.display()))?;
+        let mut numbers = BTreeMap::new();
+        let mut parts = BTreeMap::new();
+        for (day_key, entry) in raw {
+            let day_parts = match entry {
+                Entry::Number(n) => {
+                    numbers.insert(day_key, n);
+                    continue;
+                }
+                Entry::Day(day_parts) => day_parts,
+            };
+            let day = key_number(&day_key, "day").ok_or_else(|| {
+                eyre!(
+                    "{}: expected a [dayN] table, got {}",
+                    path.display(),
+                    day_key
+                )
+            })?;
+            for (part_key, table) in day_parts {
+                let part = key_number(&part_key, "part")
+                    .and_then(Part::from_number)
+                    .ok_or_else(|| {
+                        eyre!(
+                            "{}: expected part1 or part2 under {}, got {}",
+                            path.display(),
+                            day_key,
+                            part_key
+                        )
+                    })?;
+                parts.insert((day, part), table);
+            }
+        }
+        Ok(Self {
+            path: path.to_path_buf(),
+            numbers,
+            parts,
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Every entry for `part` of `day`, in key order.
+    pub fn table(&self, day: u8, part: Part) -> impl Iterator<Item = (&str, &str)> {
+        self.parts
+            .get(&(day, part))
+            .into_iter()
+            .flatten()
+            .map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    pub fn get(&self, day: u8, part: Part, key: &str) -> Option<&str> {
+        self.parts
+            .get(&(day, part))
+            .and_then(|table| table.get(key))
+            .map(String::as_str)
+    }
+
+    pub fn set(&mut self, day: u8, part: Part, key: &str, value: String) {
+        self.parts
+            .entry((day, part))
+            .or_default()
+            .insert(key.to_string(), value);
+    }
+
+    /// A number kept outside the tables.
+    pub fn number(&self, key: &str) -> Option<u64> {
+        self.numbers.get(key).copied()
+    }
+
+    pub fn set_number(&mut self, key: &str, value: u64) {
+        self.numbers.insert(key.to_string(), value);
+    }
+
+    pub fn save(&self) -> Result<()> {
+        let mut out = String::new();
+        for (key, value) in &self.numbers {
+            out.push_str(&format!("{} = {}\n", key, value));
+        }
+        for ((day, part), table) in &self.parts {
+            if !out.is_empty() {
+                out.push('\n');
+            }
+            out.push_str(&format!("[day{}.part{}]\n", day, part.number()));
+            out.push_str(&toml::to_string(table)?);
+        }
+        write(&self.path, out).wrap_err_with(|| format!("writing {}", self.path.display()))
+    }
+}
+
+/// The number in a key like `day12`, given its prefix.
+fn key_number(key: &str, prefix: &str) -> Option<u8> {
+    key.strip_prefix(prefix)?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::answer::Answer;
+    use crate::runner::{Failure, Solved, Verdict};
+    use crate::stand_in::TempDir;
+    use std::time::Duration;
+
+    fn record(day: u8, part: Part, input: &str, answer: Result<Answer, &str>) -> Record {
+        let outcome = answer
+            .map(|answer| Solved {
+                answer,
+                parse: Duration::ZERO,
+                solve: Duration::ZERO,
+            })
+            .map_err(|e| Failure::Error(eyre!("{}", e)));
+        Record {
+            year: 2021,
+            day,
+            part,
+            input: input.to_string(),
+            outcome,
+            wall: Duration::ZERO,
+        }
+    }
+
+    #[test]
+    fn round_trips_through_the_file() {
+        let root = TempDir::new("answers-round-trip");
+        std::fs::create_dir_all(&*root).unwrap();
+        let path = root.join(Answers::FILE_NAME);
+        write(
+            &path,
+            "[day1.part1]\ninput = \"877971\"\nsample = \"514579\"\n",
+        )
+        .unwrap();
+
+        let mut answers = Answers::from_file(&path).unwrap();
+        assert_eq!(answers.get(1, Part::One, "sample"), Some("514579"));
+        assert_eq!(answers.get(1, Part::Two, "sample"), None);
+        answers.set(12, Part::Two, "sample 2", "103".to_string());
+        answers.save().unwrap();
+
+        assert_eq!(
+            read_to_string(&path).unwrap(),
+            "[day1.part1]\ninput = \"877971\"\nsample = \"514579\"\n\n\
+             [day12.part2]\n\"sample 2\" = \"103\"\n"
+        );
+        let reloaded = Answers::from_file(&path).unwrap();
+        assert_eq!(reloaded.get(12, Part::Two, "sample 2"), Some("103"));
+        assert_eq!(reloaded.get(1, Part::One, "input"), Some("877971"));
+
+        write(&path, "[day1.part3]\ninput = \"1\"\n").unwrap();
+        assert!(Answers::from_file(&path).is_err());
+    }
+
+    #[test]
+    fn judges_records_against_the_answers() {
+        let root = TempDir::new("answers-verdict");
+        std::fs::create_dir_all(&*root).unwrap();
+        let path = root.join(Answers::FILE_NAME);
+        write(&path, "[day1.part1]\nsample = \"7\"\n").unwrap();
+        let mut answers = Answers::from_file(&path).unwrap();
+        let right = record(1, Part::One, "sample", Ok(7.into()));
+        let wrong = record(1, Part::One, "sample", Ok(8.into()));
+        let new = record(1, Part::Two, "sample", Ok(5.into()));
+        let failed = record(1, Part::One, "sample", Err("bad input"));
+        assert_eq!(Verdict::of(&right, &answers), Verdict::Match);
+        assert_eq!(Verdict::of(&wrong, &answers), Verdict::Mismatch);
+        assert_eq!(Verdict::of(&new, &answers), Verdict::Unrecorded);
+        assert_eq!(Verdict::of(&failed, &answers), Verdict::Failed);
+
+        // record, then verify against what was saved
+        answers.record(&[wrong, new, failed]);
+        answers.save().unwrap();
+        let answers = Answers::from_file(&path).unwrap();
+        assert_eq!(answers.get(1, Part::One, "sample"), Some("8"));
+        let rerun = [
+            record(1, Part::One, "sample", Ok(8.into())),
+            record(1, Part::Two, "sample", Ok(5.into())),
+        ];
+        assert!(rerun
+            .iter()
+            .all(|r| Verdict::of(r, &answers) == Verdict::Match));
+        assert_eq!(Verdict::of(&right, &answers), Verdict::Mismatch);
+    }
+}
@@ -0,0 +1,510 @@
+use crate::grid::{Delta, Point, NEIGHBORS4, NEIGHBORS8};
+use crate::hex::HexCoord;
+use eyre::{eyre, Report, Result};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+/// Which cells neighbour which.
+pub trait Topology {
+    type Cell: Copy + Eq + Hash;
+
+    fn neighbors(&self, cell: Self::Cell) -> Vec<Self::Cell>;
+
+    /// Every cell, if there are few enough to list. Only rules that bring
+    /// cells with no live neighbours to life need it.
+    fn cells(&self) -> Option<Vec<Self::Cell>> {
+        None
+    }
+}
+
+/// Decides whether a cell is alive next step.
+pub trait Rule {
+    fn next(&self, alive: bool, live_neighbors: usize) -> bool;
+}
+
+impl<F: Fn(bool, usize) -> bool> Rule for F {
+    fn next(&self, alive: bool, live_neighbors: usize) -> bool {
+        self(alive, live_neighbors)
+    }
+}
+
+/// Which cells a [`LifeRule`] counts as neighbours, given by the suffix
+/// of its rule string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Neighborhood {
+    /// Every touching cell, diagonals included. No suffix.
+    #[default]
+    Moore,
+    /// Only the cells sharing a side, suffix `V`.
+    VonNeumann,
+    /// The six cells around a hexagon, suffix `H`.
+    Hex,
+    /// The first cell seen in each of the eight directions, looking past
+    /// anything that isn't a cell, suffix `L` for line of sight.
+    Sight,
+}
+
+impl Neighborhood {
+    fn suffix(self) -> &'static str {
+        match self {
+            Neighborhood::Moore => "",
+            Neighborhood::VonNeumann => "V",
+            Neighborhood::Hex => "H",
+            Neighborhood::Sight => "L",
+        }
+    }
+}
+
+/// A rule in the style of Conway's Life: dead cells are born with one of
+/// the `birth` counts of live neighbours, and live cells survive with one
+/// of the `survival` counts.
+///
+/// Rules parse from strings like `B3/S23`, where each digit is a count.
+/// Counts over 9 need commas, as in `B3,10/S2`, and `<n` stands for every
+/// count below `n`, so 2020 day 11's seats are `B0/S<4`. A suffix picks a
+/// [`Neighborhood`] other than the usual eight cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LifeRule {
+    pub birth: Vec<usize>,
+    pub survival: Vec<usize>,
+    pub neighborhood: Neighborhood,
+}
+
+impl LifeRule {
+    pub fn new(birth: &[usize], survival: &[usize]) -> Self {
+        LifeRule {
+            birth: birth.to_vec(),
+            survival: survival.to_vec(),
+            neighborhood: Neighborhood::Moore,
+        }
+    }
+
+    /// An error unless the rule's neighbourhood is one of `supported`.
+    pub fn check_neighborhood(&self, supported: &[Neighborhood]) -> Result<()> {
+        if supported.contains(&self.neighborhood) {
+            Ok(())
+        } else {
+            Err(eyre!(
+                "{} uses the {:?} neighbourhood, this needs one of {:?}",
+                self,
+                self.neighborhood,
+                supported
+            ))
+        }
+    }
+
+    /// An error if the rule brings cells with no live neighbours to life,
+    /// which would fill an unbounded space.
+    pub fn check_unbounded(&self) -> Result<()> {
+        match self.birth.contains(&0) {
+            true => Err(eyre!("{} would fill unbounded space", self)),
+            false => Ok(()),
+        }
+    }
+}
+
+/// Counts like `23`, `3,10` or `<4`.
+fn parse_counts(counts: &str) -> Result<Vec<usize>> {
+    let bad = || eyre!("bad neighbour counts {:?}", counts);
+    let mut parsed: Vec<usize> = if let Some(below) = counts.strip_prefix('<') {
+        (0..below.parse::<usize>().map_err(|_| bad())?).collect()
+    } else if counts.contains(',') {
+        counts
+            .split(',')
+            .map(|n| n.parse().map_err(|_| bad()))
+            .collect::<Result<_>>()?
+    } else {
+        counts
+            .chars()
+            .map(|c| c.to_digit(10).map(|n| n as usize).ok_or_else(bad))
+            .collect::<Result<_>>()?
+    };
+    parsed.sort_unstable();
+    parsed.dedup();
+    Ok(parsed)
+}
+
+impl FromStr for LifeRule {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let (rule, neighborhood) = match s.char_indices().last() {
+            Some((i, 'V')) => (&s[..i], Neighborhood::VonNeumann),
+            Some((i, 'H')) => (&s[..i], Neighborhood::Hex),
+            Some((i, 'L')) => (&s[..i], Neighborhood::Sight),
+            _ => (s, Neighborhood::Moore),
+        };
+        let (birth, survival) = rule
+            .strip_prefix('B')
+            .and_then(|rule| rule.split_once("/S"))
+            .ok_or_else(|| eyre!("expected a rule like B3/S23, found {:?}", s))?;
+        Ok(LifeRule {
+            birth: parse_counts(birth)?,
+            survival: parse_counts(survival)?,
+            neighborhood,
+        })
+    }
+}
+
+impl fmt::Display for LifeRule {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let counts = |counts: &[usize]| {
+            let counts: Vec<_> = counts.iter().map(usize::to_string).collect();
+            match counts.iter().all(|n| n.len() == 1) {
+                true => counts.concat(),
+                false => counts.join(","),
+            }
+        };
+        write!(
+            f,
+            "B{}/S{}{}",
+            counts(&self.birth),
+            counts(&self.survival),
+            self.neighborhood.suffix()
+        )
+    }
+}
+
+impl Rule for LifeRule {
+    fn next(&self, alive: bool, live_neighbors: usize) -> bool {
+        if alive {
+            self.survival.contains(&live_neighbors)
+        } else {
+            self.birth.contains(&live_neighbors)
+        }
+    }
+}
+
+/// Unbounded `N`-dimensional space where every cell touching a cell,
+/// diagonals included, neighbours it.
+#[derive(Debug, Clone)]
+pub struct Cube<const N: usize> {
+    offsets: Vec<[isize; N]>,
+}
+
+impl<const N: usize> Cube<N> {
+    /// Space where only cells one step along a single axis neighbour.
+    pub fn von_neumann() -> Self {
+        let offsets = (0..N)
+            .flat_map(|axis| {
+                [-1, 1].map(|step| {
+                    let mut offset = [0; N];
+                    offset[axis] = step;
+                    offset
+                })
+            })
+            .collect();
+        Cube { offsets }
+    }
+
+    pub fn new() -> Self {
+        let mut offsets = vec![[0; N]];
+        for axis in 0..N {
+            offsets = offsets
+                .into_iter()
+                .flat_map(|offset| {
+                    (-1..=1).map(move |step| {
+                        let mut offset = offset;
+                        offset[axis] = step;
+                        offset
+                    })
+                })
+                .collect();
+        }
+        offsets.retain(|offset| offset.iter().any(|&step| step != 0));
+        Cube { offsets }
+    }
+}
+
+impl<const N: usize> Default for Cube<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Topology for Cube<N> {
+    type Cell = [isize; N];
+
+    fn neighbors(&self, cell: Self::Cell) -> Vec<Self::Cell> {
+        self.offsets
+            .iter()
+            .map(|offset| {
+                let mut neighbor = cell;
+                for (n, step) in neighbor.iter_mut().zip(offset) {
+                    *n += step;
+                }
+                neighbor
+            })
+            .collect()
+    }
+}
+
+/// An unbounded floor of hexagons.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Hex;
+
+impl Topology for Hex {
+    type Cell = HexCoord;
+
+    fn neighbors(&self, cell: HexCoord) -> Vec<HexCoord> {
+        cell.neighbors().to_vec()
+    }
+}
+
+/// A `width` by `height` grid, where neighbours are the cells `deltas`
+/// away that are still on it.
+#[derive(Debug, Clone)]
+pub struct Bounded {
+    pub width: usize,
+    pub height: usize,
+    pub deltas: Vec<Delta>,
+}
+
+impl Bounded {
+    /// A grid where all eight surrounding cells are neighbours.
+    pub fn new(width: usize, height: usize) -> Self {
+        Bounded {
+            width,
+            height,
+            deltas: NEIGHBORS8.to_vec(),
+        }
+    }
+
+    /// A grid where only the four cells sharing a side are neighbours.
+    pub fn von_neumann(width: usize, height: usize) -> Self {
+        Bounded {
+            width,
+            height,
+            deltas: NEIGHBORS4.to_vec(),
+        }
+    }
+}
+
+impl Topology for Bounded {
+    type Cell = Point;
+
+    fn neighbors(&self, (x, y): Point) -> Vec<Point> {
+        self.deltas
+            .iter()
+            .filter_map(|&(dx, dy)| {
+                let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+                let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+                Some((x, y))
+            })
+            .collect()
+    }
+
+    fn cells(&self) -> Option<Vec<Point>> {
+        let width = self.width;
+        Some(
+            (0..self.height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .collect(),
+        )
+    }
+}
+
+/// A cellular automaton that only keeps track of its live cells, so it
+/// can grow without bound.
+#[derive(Debug, Clone)]
+pub struct Automaton<T: Topology, R> {
+    topology: T,
+    rule: R,
+    alive: HashSet<T::Cell>,
+}
+
+impl<T: Topology, R: Rule> Automaton<T, R> {
+    pub fn new(topology: T, rule: R, alive: impl IntoIterator<Item = T::Cell>) -> Self {
+        Automaton {
+            topology,
+            rule,
+            alive: alive.into_iter().collect(),
+        }
+    }
+
+    pub fn alive(&self) -> &HashSet<T::Cell> {
+        &self.alive
+    }
+
+    pub fn is_alive(&self, cell: T::Cell) -> bool {
+        self.alive.contains(&cell)
+    }
+
+    pub fn count_alive(&self) -> usize {
+        self.alive.len()
+    }
+
+    /// Advance one generation. Only live cells and their neighbours can
+    /// change, unless the rule brings isolated cells to life.
+    ///
+    /// # Panics
+    ///
+    /// If the rule brings isolated cells to life on a topology that can't
+    /// list its cells.
+    pub fn step(&mut self) {
+        let mut live_neighbors: HashMap<T::Cell, usize> = HashMap::new();
+        for &cell in &self.alive {
+            for neighbor in self.topology.neighbors(cell) {
+                *live_neighbors.entry(neighbor).or_default() += 1;
+            }
+        }
+
+        let candidates: Vec<T::Cell> = if self.rule.next(false, 0) {
+            self.topology
+                .cells()
+                .expect("births from no neighbours need a finite topology")
+        } else {
+            live_neighbors.keys().chain(&self.alive).copied().collect()
+        };
+        self.alive = candidates
+            .into_iter()
+            .filter(|cell| {
+                let count = live_neighbors.get(cell).copied().unwrap_or(0);
+                self.rule.next(self.alive.contains(cell), count)
+            })
+            .collect();
+    }
+
+    /// Advance `generations` generations.
+    pub fn run(&mut self, generations: usize) {
+        for _ in 0..generations {
+            self.step();
+        }
+    }
+
+    /// Advance until a generation changes nothing, returning how many
+    /// generations that took.
+    ///
+    /// Fails if the cells fall into a cycle instead of settling, or are
+    /// still changing after `limit` generations.
+    pub fn run_until_stable(&mut self, limit: usize) -> Result<usize> {
+        // every earlier state with how many steps in it was seen, filed by
+        // fingerprint so only states that might be equal get compared
+        let mut seen: HashMap<u64, Vec<_>> = HashMap::new();
+        seen.entry(self.fingerprint())
+            .or_default()
+            .push((0, self.alive.clone()));
+        for generation in 0..limit {
+            let before = self.alive.clone();
+            self.step();
+            if self.alive == before {
+                return Ok(generation);
+            }
+            let same_fingerprint = seen.entry(self.fingerprint()).or_default();
+            if let Some((first, _)) = same_fingerprint
+                .iter()
+                .find(|(_, alive)| *alive == self.alive)
+            {
+                return Err(eyre!(
+                    "rule never settles, cycles with period {}",
+                    generation + 1 - first
+                ));
+            }
+            same_fingerprint.push((generation + 1, self.alive.clone()));
+        }
+        Err(eyre!("rule hasn't settled after {} generations", limit))
+    }
+
+    /// A hash of the live cells that doesn't depend on the set's order.
+    fn fingerprint(&self) -> u64 {
+        self.alive
+            .iter()
+            .map(|cell| {
+                let mut hasher = DefaultHasher::new();
+                cell.hash(&mut hasher);
+                hasher.finish()
+            })
+            .fold(0, u64::wrapping_add)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn life() -> LifeRule {
+        LifeRule::new(&[3], &[2, 3])
+    }
+
+    #[test]
+    fn parses_rule_strings() {
+        assert_eq!("B3/S23".parse::<LifeRule>().unwrap(), life());
+        assert_eq!(
+            "B0/S<4".parse::<LifeRule>().unwrap(),
+            LifeRule::new(&[0], &[0, 1, 2, 3])
+        );
+
+        let rule: LifeRule = "B2/S12H".parse().unwrap();
+        assert_eq!(rule.neighborhood, Neighborhood::Hex);
+        assert_eq!(rule.to_string(), "B2/S12H");
+
+        let rule: LifeRule = "B3,10/S<5L".parse().unwrap();
+        assert_eq!(
+            (rule.birth.as_slice(), rule.neighborhood),
+            (&[3, 10][..], Neighborhood::Sight)
+        );
+        assert_eq!(rule.to_string(), "B3,10/S01234L");
+
+        for bad in ["", "B3", "S23/B3", "B3/S2x", "B3/S<", "B3/S23Q"] {
+            assert!(bad.parse::<LifeRule>().is_err(), "{:?} parsed", bad);
+        }
+    }
+
+    #[test]
+    fn cube_neighbors() {
+        assert_eq!(Cube::<3>::von_neumann().neighbors([0, 0, 0]).len(), 6);
+        assert_eq!(Cube::<2>::new().neighbors([0, 0]).len(), 8);
+        assert_eq!(Cube::<3>::new().neighbors([0, 0, 0]).len(), 26);
+        assert_eq!(Cube::<4>::new().neighbors([0, 0, 0, 0]).len(), 80);
+    }
+
+    #[test]
+    fn glider_glides() {
+        let glider = [[1, 0], [2, 1], [0, 2], [1, 2], [2, 2]];
+        let mut automaton = Automaton::new(Cube::<2>::new(), life(), glider);
+        automaton.run(4);
+
+        let moved: HashSet<_> = glider.iter().map(|[x, y]| [x + 1, y + 1]).collect();
+        assert_eq!(automaton.alive(), &moved);
+    }
+
+    #[test]
+    fn blinker_blinks_in_a_box() {
+        let mut automaton = Automaton::new(Bounded::new(3, 3), life(), [(0, 1), (1, 1), (2, 1)]);
+        automaton.step();
+        assert!(automaton.is_alive((1, 0)) && automaton.is_alive((1, 2)));
+        assert_eq!(automaton.count_alive(), 3);
+
+        let cycle = automaton.run_until_stable(100).unwrap_err();
+        assert_eq!(
+            cycle.to_string(),
+            "rule never settles, cycles with period 2"
+        );
+    }
+
+    #[test]
+    fn gives_up_on_a_glider() {
+        let glider = [[1, 0], [2, 1], [0, 2], [1, 2], [2, 2]];
+        let mut automaton = Automaton::new(Cube::<2>::new(), life(), glider);
+        let error = automaton.run_until_stable(20).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "rule hasn't settled after 20 generations"
+        );
+
+        let block = [[0, 0], [0, 1], [1, 0], [1, 1]];
+        let mut automaton = Automaton::new(Cube::<2>::new(), life(), block);
+        assert_eq!(automaton.run_until_stable(20).unwrap(), 0);
+    }
+
+    #[test]
+    fn births_from_nothing_fill_a_box() {
+        let rule = |alive: bool, live_neighbors: usize| !alive && live_neighbors == 0;
+        let mut automaton = Automaton::new(Bounded::new(2, 2), rule, []);
+        automaton.step();
+        assert_eq!(automaton.count_alive(), 4);
+    }
+}
@@ -0,0 +1,252 @@
+use crate::answer::Answer;
+use crate::day::Part;
+use crate::input::{Input, InputProvider, Selection};
+use crate::runner::Solution;
+use eyre::{eyre, Result, WrapErr};
+use serde::{Serialize, Serializer};
+use std::fmt;
+use std::time::Duration;
+
+/// Summary statistics over a set of timings.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Stats {
+    #[serde(rename = "min_ns", serialize_with = "nanos")]
+    pub min: Duration,
+    #[serde(rename = "median_ns", serialize_with = "nanos")]
+    pub median: Duration,
+    #[serde(rename = "mean_ns", serialize_with = "nanos")]
+    pub mean: Duration,
+    #[serde(rename = "stddev_ns", serialize_with = "nanos")]
+    pub stddev: Duration,
+}
+
+impl Stats {
+    /// Summarise `timings`, which mustn't be empty.
+    pub fn of(timings: &[Duration]) -> Self {
+        let mut sorted = timings.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = match n % 2 {
+            0 => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+            _ => sorted[n / 2],
+        };
+        let mean = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|t| (t.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+        Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "min {:.2?}, median {:.2?}, mean {:.2?}, stddev {:.2?}",
+            self.min, self.median, self.mean, self.stddev
+        )
+    }
+}
+
+fn nanos<S: Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+    s.serialize_u64(d.as_nanos() as u64)
+}
+
+/// The timings of one part against one input over repeated runs.
+#[derive(Debug, Clone, Serialize)]
+pub struct Bench {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input: String,
+    pub answer: Answer,
+    pub warmup: usize,
+    pub runs: usize,
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+impl fmt::Display for Bench {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{} day {} part {}, {}: {} ({} runs after {} warm-up)",
+            self.year, self.day, self.part, self.input, self.answer, self.runs, self.warmup
+        )?;
+        writeln!(f, "  parse  {}", self.parse)?;
+        write!(f, "  solve  {}", self.solve)
+    }
+}
+
+/// The inputs to time `part` against: only the real one unless an input
+/// was picked, since samples are usually too small to be worth timing.
+pub fn inputs(provider: &InputProvider, part: Part, samples: &[u8]) -> Result<Vec<Input>> {
+    match provider.selection() {
+        Selection::All => Ok(vec![provider.real()?]),
+        _ => provider.inputs(part, samples),
+    }
+}
+
+/// Solve `part` against `input` `warmup` times without keeping the
+/// timings, then `runs` more times, timing parsing and solving separately.
+pub fn bench(
+    solution: &dyn Solution,
+    (year, day): (u16, u8),
+    part: Part,
+    input: &Input,
+    warmup: usize,
+    runs: usize,
+) -> Result<Bench> {
+    if runs == 0 {
+        return Err(eyre!("need at least one run to benchmark"));
+    }
+    let solve = || {
+        solution
+            .solve(part, input)
+            .wrap_err_with(|| format!("solving {} with {}", part, input.name))
+    };
+    for _ in 0..warmup {
+        solve()?;
+    }
+    let mut parses = Vec::with_capacity(runs);
+    let mut solves = Vec::with_capacity(runs);
+    let mut answer = None;
+    for _ in 0..runs {
+        let solved = solve()?;
+        parses.push(solved.parse);
+        solves.push(solved.solve);
+        answer = Some(solved.answer);
+    }
+    Ok(Bench {
+        year,
+        day,
+        part: part.number(),
+        input: input.name.clone(),
+        answer: answer.expect("at least one run"),
+        warmup,
+        runs,
+        parse: Stats::of(&parses),
+        solve: Stats::of(&solves),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::data::DataDirs;
+    use crate::input::{Layout, Source};
+    use crate::stand_in::TempDir;
+    use crate::Day;
+    use std::cell::Cell;
+    use std::fs::{create_dir_all, write};
+
+    const LAYOUT: Layout = Layout {
+        year: 2015,
+        dir: "/nonexistent",
+        input: |day| format!("day{}", day),
+        sample: |day, n| format!("day{}-sample{}", day, n),
+        source: Source {
+            dir: "/nonexistent",
+            module: |day| format!("day{}.rs", day),
+            declarations: "main.rs",
+        },
+    };
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn summarises_timings() {
+        let stats = Stats::of(&[ms(4), ms(1), ms(3), ms(2)]);
+        assert_eq!(
+            (stats.min, stats.median),
+            (ms(1), Duration::from_micros(2500))
+        );
+        assert_eq!(stats.mean.as_micros(), 2500);
+        assert_eq!(stats.stddev.as_micros(), 1118);
+        assert_eq!(Stats::of(&[ms(5), ms(1), ms(9)]).median, ms(5));
+        assert_eq!(
+            stats.to_string(),
+            "min 1.00ms, median 2.50ms, mean 2.50ms, stddev 1.12ms"
+        );
+        assert_eq!(
+            serde_json::to_string(&Stats::of(&[Duration::from_nanos(7)])).unwrap(),
+            r#"{"min_ns":7,"median_ns":7,"mean_ns":7,"stddev_ns":0}"#
+        );
+    }
+
+    #[test]
+    fn times_the_real_input_unless_told_otherwise() {
+        let root = TempDir::new("bench-inputs");
+        create_dir_all(root.join("2015")).unwrap();
+        for (file, text) in [
+            ("day1", "real"),
+            ("day1-sample1", "s1"),
+            ("day1-sample2", "s2"),
+        ] {
+            write(root.join("2015").join(file), text).unwrap();
+        }
+        let dirs = DataDirs::resolve(Some(&root), &Config::default(), &LAYOUT);
+        let names = |selection| {
+            let provider = InputProvider::new(&LAYOUT, dirs.clone(), 1, selection);
+            inputs(&provider, Part::One, &[1, 2])
+                .unwrap()
+                .into_iter()
+                .map(|input| input.name)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(names(Selection::All), ["input"]);
+        assert_eq!(names(Selection::Sample(2)), ["sample 2"]);
+    }
+
+    /// Counts how many times it's solved.
+    #[derive(Default)]
+    struct Counter(Cell<usize>);
+
+    impl Day for Counter {
+        type Input<'a> = ();
+
+        fn parse<'a>(&self, _input: &'a str) -> Result<Self::Input<'a>> {
+            Ok(())
+        }
+
+        fn part_1(&self, _input: &Self::Input<'_>) -> Result<Answer> {
+            self.0.set(self.0.get() + 1);
+            Ok(self.0.get().into())
+        }
+
+        fn part_2(&self, _input: &Self::Input<'_>) -> Result<Answer> {
+            Err(eyre!("no part 2"))
+        }
+    }
+
+    #[test]
+    fn warms_up_before_timing() {
+        let counter = Counter::default();
+        let input = Input {
+            name: "sample".to_string(),
+            text: String::new(),
+        };
+        let timed = bench(&counter, (2015, 1), Part::One, &input, 3, 5).unwrap();
+        assert_eq!(counter.0.get(), 8);
+        assert_eq!(timed.answer.to_string(), "8");
+        assert_eq!((timed.warmup, timed.runs), (3, 5));
+        assert!(timed
+            .to_string()
+            .starts_with("2015 day 1 part 1, sample: 8 (5 runs after 3 warm-up)\n  parse  min "));
+
+        assert!(bench(&counter, (2015, 1), Part::One, &input, 0, 0).is_err());
+        let failed = bench(&counter, (2015, 1), Part::Two, &input, 0, 1).unwrap_err();
+        assert_eq!(failed.to_string(), "solving part 2 with sample");
+    }
+}
@@ -0,0 +1,467 @@
+use crate::answers::{Answers, Expected};
+use crate::bench;
+use crate::config::Config;
+use crate::data::DataDirs;
+use crate::day::Part;
+use crate::extract::{self, Written};
+use crate::fetch::{self, Fetched};
+use crate::input::{InputProvider, Layout, Selection};
+use crate::registry::{self, Registration};
+use crate::runner::{self, Verdict};
+use crate::scaffold::{self, Created};
+use crate::submit::{self, Guesses, Outcome};
+use clap::{Args, CommandFactory, FromArgMatches, Parser, Subcommand, ValueEnum};
+use eyre::{eyre, Result, WrapErr};
+use std::path::PathBuf;
+
+/// The command line shared by every year's binary. Without a subcommand
+/// it behaves like `run`.
+#[derive(Debug, Parser)]
+#[command(args_conflicts_with_subcommands = true)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+    #[command(flatten)]
+    pub run: RunArgs,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Run a day, or every day
+    Run(RunArgs),
+    /// List every registered day and its parts
+    List,
+    /// Check every part's answers against the recorded answers file
+    Verify(CheckArgs),
+    /// Write every part's current answers into the answers file
+    Record(CheckArgs),
+    /// Time a day's parts over repeated runs
+    Bench(BenchArgs),
+    /// Download puzzle inputs that aren't in a data directory yet
+    Fetch(FetchArgs),
+    /// Solve a part against the real input and submit the answer
+    Submit(SubmitArgs),
+    /// Start a new day from a template
+    New(NewArgs),
+    /// Write a day's samples and their expected answers from a saved
+    /// puzzle page
+    Extract(ExtractArgs),
+}
+
+/// How results are written to stdout.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human readable
+    #[default]
+    Text,
+    /// One JSON object per line
+    Json,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct RunArgs {
+    /// Challenge day, defaults to the latest one
+    #[arg(short, long)]
+    pub day: Option<u8>,
+    /// Run every registered day and summarise the results in a table
+    #[arg(long, conflicts_with_all = ["day", "input"])]
+    pub all: bool,
+    /// How to write the results
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+    /// Set one of the day's own options, e.g. rule=B36/S23 to simulate a
+    /// cellular automaton day with another rule. Can be repeated
+    #[arg(
+        long = "set",
+        value_name = "KEY=VALUE",
+        value_parser = key_value,
+        conflicts_with_all = ["all", "format"]
+    )]
+    pub options: Vec<(String, String)>,
+    #[command(flatten)]
+    pub input: InputArgs,
+}
+
+/// Flags for `verify` and `record`, which always run against every sample
+/// and the real input.
+#[derive(Debug, Clone, Args)]
+pub struct CheckArgs {
+    /// Challenge day, defaults to every day
+    #[arg(short, long)]
+    pub day: Option<u8>,
+    /// Root directory holding a subdirectory of inputs for each year
+    #[arg(long, value_name = "DIR")]
+    pub data_dir: Option<PathBuf>,
+}
+
+impl CheckArgs {
+    /// Run the selected days against all of their inputs.
+    fn run(&self, layout: &Layout) -> Result<(DataDirs, Vec<runner::Record>)> {
+        let days = match self.day {
+            Some(day) => vec![registry::find(layout.year, day)?],
+            None => registry::days(layout.year),
+        };
+        let dirs = DataDirs::resolve(self.data_dir.as_deref(), &Config::load()?, layout);
+        let records = runner::run_all(&days, |day| {
+            InputProvider::new(layout, dirs.clone(), day, Selection::All)
+        });
+        Ok((dirs, records))
+    }
+}
+
+/// Flags for `bench`. Without `--input` or `--sample` it times the real
+/// input.
+#[derive(Debug, Clone, Args)]
+pub struct BenchArgs {
+    /// Challenge day, defaults to the latest one
+    #[arg(short, long)]
+    pub day: Option<u8>,
+    /// Only time this part, defaults to both
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+    /// Number of timed runs
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    pub runs: u32,
+    /// Number of untimed runs beforehand
+    #[arg(short, long, default_value_t = 3)]
+    pub warmup: u32,
+    /// How to write the timings
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+    #[command(flatten)]
+    pub input: InputArgs,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct FetchArgs {
+    /// Challenge day, defaults to every registered day
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: Option<u8>,
+    /// Root directory holding a subdirectory of inputs for each year
+    #[arg(long, value_name = "DIR")]
+    pub data_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct SubmitArgs {
+    /// Challenge day, defaults to the latest one
+    #[arg(short, long)]
+    pub day: Option<u8>,
+    /// The part to submit
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: u8,
+    /// Root directory holding a subdirectory of inputs for each year
+    #[arg(long, value_name = "DIR")]
+    pub data_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct NewArgs {
+    /// Defaults to this binary's year, the only one it can add days to
+    #[arg(short, long)]
+    pub year: Option<u16>,
+    /// The day to start
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct ExtractArgs {
+    /// Challenge day, defaults to the latest one
+    #[arg(short, long)]
+    pub day: Option<u8>,
+    /// The puzzle page, saved from the browser
+    #[arg(value_name = "HTML")]
+    pub html: PathBuf,
+    /// Replace samples that differ from the page's examples
+    #[arg(long)]
+    pub force: bool,
+}
+
+/// Parse the command line and carry it out for `layout`'s year.
+pub fn main(layout: &Layout) -> Result<()> {
+    let matches = Cli::command()
+        .name("aoc")
+        .about(format!("Advent of Code, {}", layout.year))
+        .get_matches();
+    let cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
+
+    match cli.command {
+        Some(Command::Run(args)) => run(layout, &args),
+        Some(Command::List) => {
+            for registration in registry::all() {
+                for part in Part::ALL {
+                    println!("{} day {} {}", registration.year, registration.day, part);
+                }
+            }
+            Ok(())
+        }
+        Some(Command::Verify(args)) => verify(layout, &args),
+        Some(Command::Record(args)) => record(layout, &args),
+        Some(Command::Bench(args)) => bench(layout, &args),
+        Some(Command::Fetch(args)) => fetch(layout, &args),
+        Some(Command::Submit(args)) => submit(layout, &args),
+        Some(Command::New(args)) => new(layout, &args),
+        Some(Command::Extract(args)) => extract(layout, &args),
+        None => run(layout, &cli.run),
+    }
+}
+
+fn run(layout: &Layout, args: &RunArgs) -> Result<()> {
+    if !args.all && args.format == Format::Text {
+        let registration = find_day(layout, args.day)?;
+        let provider = args.input.provider(layout, registration.day)?;
+        let mut solution = (registration.solution)();
+        for (key, value) in &args.options {
+            solution.configure(key, value).wrap_err_with(|| {
+                format!("setting {}={} on day {}", key, value, registration.day)
+            })?;
+        }
+        return runner::run(&provider, solution.as_ref());
+    }
+
+    let days = match args.all {
+        true => registry::days(layout.year),
+        false => vec![find_day(layout, args.day)?],
+    };
+    let dirs = args.input.dirs(layout)?;
+    let records = runner::run_all(&days, |day| {
+        InputProvider::new(layout, dirs.clone(), day, args.input.selection())
+    });
+    match args.format {
+        Format::Text => runner::print_table(&records),
+        Format::Json => {
+            for record in &records {
+                println!("{}", serde_json::to_string(record)?);
+            }
+        }
+    }
+    runner::check_succeeded(&records)
+}
+
+/// The registration for `day`, or the latest day if not given.
+fn find_day(layout: &Layout, day: Option<u8>) -> Result<&'static Registration> {
+    match day {
+        Some(day) => registry::find(layout.year, day),
+        None => registry::days(layout.year)
+            .last()
+            .copied()
+            .ok_or_else(|| eyre!("no days registered for {}", layout.year)),
+    }
+}
+
+/// Split a `--set` option into its key and value.
+fn key_value(option: &str) -> Result<(String, String), String> {
+    let (key, value) = option
+        .split_once('=')
+        .ok_or_else(|| format!("expected KEY=VALUE, got {:?}", option))?;
+    Ok((key.to_owned(), value.to_owned()))
+}
+
+fn verify(layout: &Layout, args: &CheckArgs) -> Result<()> {
+    let (dirs, records) = args.run(layout)?;
+    let answers = Answers::load(&dirs)?;
+    runner::print_verified(&records, &answers);
+    let bad = records
+        .iter()
+        .filter(|r| {
+            matches!(
+                Verdict::of(r, &answers),
+                Verdict::Mismatch | Verdict::Failed
+            )
+        })
+        .count();
+    match bad {
+        0 => Ok(()),
+        _ => Err(eyre!(
+            "{} of {} answers didn't match {}",
+            bad,
+            records.len(),
+            answers.path().display()
+        )),
+    }
+}
+
+fn record(layout: &Layout, args: &CheckArgs) -> Result<()> {
+    let (dirs, records) = args.run(layout)?;
+    let mut answers = Answers::load(&dirs)?;
+    runner::print_table(&records);
+    answers.record(&records);
+    answers.save()?;
+    let failed = records.iter().filter(|r| r.outcome.is_err()).count();
+    println!();
+    println!(
+        "recorded {} answers in {}",
+        records.len() - failed,
+        answers.path().display()
+    );
+    match failed {
+        0 => Ok(()),
+        _ => Err(eyre!(
+            "{} of {} runs failed and weren't recorded",
+            failed,
+            records.len()
+        )),
+    }
+}
+
+fn bench(layout: &Layout, args: &BenchArgs) -> Result<()> {
+    let registration = find_day(layout, args.day)?;
+    let solution = (registration.solution)();
+    let provider = args.input.provider(layout, registration.day)?;
+    let parts = match args.part.and_then(Part::from_number) {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    };
+    for part in parts {
+        for input in bench::inputs(&provider, part, solution.samples(part))? {
+            let bench = bench::bench(
+                solution.as_ref(),
+                (registration.year, registration.day),
+                part,
+                &input,
+                args.warmup as usize,
+                args.runs as usize,
+            )?;
+            match args.format {
+                Format::Text => println!("{}", bench),
+                Format::Json => println!("{}", serde_json::to_string(&bench)?),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn fetch(layout: &Layout, args: &FetchArgs) -> Result<()> {
+    let config = Config::load()?;
+    let dirs = DataDirs::resolve(args.data_dir.as_deref(), &config, layout);
+    let client = fetch::Client::from_config(&config);
+    let days = match args.day {
+        Some(day) => vec![day],
+        None => registry::days(layout.year).iter().map(|r| r.day).collect(),
+    };
+    for day in days {
+        match fetch::input(&client, layout, &dirs, day)? {
+            Fetched::Cached(path) => println!("day {}: cached at {}", day, path.display()),
+            Fetched::Downloaded(path) => {
+                println!("day {}: downloaded to {}", day, path.display())
+            }
+        }
+    }
+    Ok(())
+}
+
+fn submit(layout: &Layout, args: &SubmitArgs) -> Result<()> {
+    let registration = find_day(layout, args.day)?;
+    let part = Part::from_number(args.part).expect("clap checks the part");
+    let config = Config::load()?;
+    let dirs = DataDirs::resolve(args.data_dir.as_deref(), &config, layout);
+    let provider = InputProvider::new(layout, dirs.clone(), registration.day, Selection::All);
+    let input = provider.real()?;
+    let solved = (registration.solution)()
+        .solve(part, &input)
+        .wrap_err_with(|| format!("solving {} with {}", part, input.name))?;
+    println!(
+        "{} day {} {}: {}",
+        layout.year, registration.day, part, solved.answer
+    );
+
+    let mut guesses = Guesses::load(&dirs)?;
+    let outcome = submit::submit(
+        &fetch::Client::from_config(&config),
+        &mut guesses,
+        (layout.year, registration.day),
+        part,
+        &solved.answer,
+    )?;
+    println!("{}", outcome);
+    match outcome {
+        Outcome::Correct => Ok(()),
+        _ => Err(eyre!("{} wasn't accepted", solved.answer)),
+    }
+}
+
+fn new(layout: &Layout, args: &NewArgs) -> Result<()> {
+    if let Some(year) = args.year.filter(|&year| year != layout.year) {
+        return Err(eyre!(
+            "this is the {} binary, run `cargo run -p aoc-{} -- new` to add a day to {}",
+            layout.year,
+            year,
+            year
+        ));
+    }
+    for created in scaffold::new_day(layout, args.day)? {
+        match created {
+            Created::File(path) => println!("created {}", path.display()),
+            Created::Declaration(path) => println!("declared in {}", path.display()),
+            Created::Kept(path) => println!("kept {}", path.display()),
+        }
+    }
+    Ok(())
+}
+
+fn extract(layout: &Layout, args: &ExtractArgs) -> Result<()> {
+    let day = find_day(layout, args.day)?.day;
+    for written in extract::write_samples(layout, day, &args.html, args.force)? {
+        match written {
+            Written::Sample(path) => println!("wrote {}", path.display()),
+            Written::Unchanged(path) => println!("unchanged {}", path.display()),
+            Written::Expected(path) => {
+                let expected = Expected::load(&path)?;
+                println!(
+                    "wrote {} (part 1: {}, part 2: {})",
+                    path.display(),
+                    expected.part1.as_deref().unwrap_or("-"),
+                    expected.part2.as_deref().unwrap_or("-")
+                )
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Flags for choosing which input a day runs against. With neither flag,
+/// each part runs against its samples and then the real input.
+#[derive(Debug, Clone, Args)]
+#[command(about = None, long_about = None)]
+pub struct InputArgs {
+    /// Read the puzzle input from this file, or from stdin if given `-`
+    #[arg(short, long, value_name = "PATH", conflicts_with = "sample")]
+    pub input: Option<PathBuf>,
+    /// Only run against the nth sample
+    #[arg(short, long, value_name = "N", value_parser = clap::value_parser!(u8).range(1..))]
+    pub sample: Option<u8>,
+    /// Root directory holding a subdirectory of inputs for each year
+    #[arg(long, value_name = "DIR")]
+    pub data_dir: Option<PathBuf>,
+}
+
+impl InputArgs {
+    pub fn selection(&self) -> Selection {
+        match (&self.input, self.sample) {
+            (Some(path), _) if path.as_os_str() == "-" => Selection::Stdin,
+            (Some(path), _) => Selection::File(path.clone()),
+            (None, Some(n)) => Selection::Sample(n),
+            (None, None) => Selection::All,
+        }
+    }
+
+    /// Where to look for `layout`'s inputs, honouring `--data-dir`.
+    pub fn dirs(&self, layout: &Layout) -> Result<DataDirs> {
+        Ok(DataDirs::resolve(
+            self.data_dir.as_deref(),
+            &Config::load()?,
+            layout,
+        ))
+    }
+
+    /// An input provider for `day` honouring these flags.
+    pub fn provider<'a>(&self, layout: &'a Layout, day: u8) -> Result<InputProvider<'a>> {
+        Ok(InputProvider::new(
+            layout,
+            self.dirs(layout)?,
+            day,
+            self.selection(),
+        ))
+    }
+}
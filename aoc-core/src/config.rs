@@ -0,0 +1,47 @@
+use eyre::{Result, WrapErr};
+use serde::Deserialize;
+use std::fs::read_to_string;
+use std::path::{Path, PathBuf};
+
+/// Settings read from an `aoc.toml`, found by searching upwards from the
+/// current directory.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Config {
+    /// Root directory holding a subdirectory of inputs per year. Relative
+    /// paths are taken from the directory containing the config file.
+    pub data_dir: Option<PathBuf>,
+    /// Session cookie for the puzzle server, used when `$AOC_SESSION`
+    /// isn't set.
+    pub session: Option<String>,
+    /// The file this config was read from, if any.
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+}
+
+impl Config {
+    pub const FILE_NAME: &'static str = "aoc.toml";
+
+    /// Load the nearest `aoc.toml`, or an empty config if there isn't one.
+    pub fn load() -> Result<Self> {
+        let cwd = std::env::current_dir().wrap_err("finding current directory")?;
+        match cwd
+            .ancestors()
+            .map(|dir| dir.join(Self::FILE_NAME))
+            .find(|path| path.is_file())
+        {
+            Some(path) => Self::from_file(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let raw = read_to_string(path).wrap_err_with(|| format!("reading {}", path.display()))?;
+        let mut config: Config =
+            toml::from_str(&raw).wrap_err_with(|| format!("parsing {}", path.display()))?;
+        let base = path.parent().unwrap_or_else(|| Path::new("."));
+        config.data_dir = config.data_dir.map(|dir| base.join(dir));
+        config.path = Some(path.to_path_buf());
+        Ok(config)
+    }
+}
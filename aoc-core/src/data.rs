@@ -0,0 +1,185 @@
+use crate::config::Config;
+use crate::input::Layout;
+use eyre::{eyre, Result};
+use std::ffi::OsString;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+/// Environment variable naming the root data directory.
+pub const DATA_DIR_VAR: &str = "AOC_DATA_DIR";
+
+/// The directories a year's inputs are looked for in, highest priority
+/// first:
+///
+/// 1. the `--data-dir` flag,
+/// 2. the `AOC_DATA_DIR` environment variable,
+/// 3. `data_dir` in the nearest `aoc.toml`,
+/// 4. the year's own directory inside its package.
+///
+/// The first three name a root holding one subdirectory per year. None of
+/// them depend on the working directory, so `cargo run` works from anywhere.
+#[derive(Debug, Clone)]
+pub struct DataDirs {
+    /// Each directory along with where it came from.
+    dirs: Vec<(String, PathBuf)>,
+}
+
+impl DataDirs {
+    pub fn resolve(flag: Option<&Path>, config: &Config, layout: &Layout) -> Self {
+        Self::from_sources(flag, std::env::var_os(DATA_DIR_VAR), config, layout)
+    }
+
+    /// [`DataDirs::resolve`] with the environment variable's value passed
+    /// in.
+    fn from_sources(
+        flag: Option<&Path>,
+        env: Option<OsString>,
+        config: &Config,
+        layout: &Layout,
+    ) -> Self {
+        let year = layout.year.to_string();
+        let mut dirs = vec![];
+        if let Some(root) = flag {
+            dirs.push(("--data-dir".to_string(), root.join(&year)));
+        }
+        if let Some(root) = env {
+            dirs.push((
+                format!("${}", DATA_DIR_VAR),
+                PathBuf::from(root).join(&year),
+            ));
+        }
+        if let (Some(root), Some(path)) = (&config.data_dir, &config.path) {
+            dirs.push((path.display().to_string(), root.join(&year)));
+        }
+        dirs.push(("package default".to_string(), PathBuf::from(layout.dir)));
+        Self { dirs }
+    }
+
+    /// The highest priority directory.
+    pub fn primary(&self) -> &Path {
+        &self.dirs[0].1
+    }
+
+    /// The path of `file_name` in the first directory that has it, if any
+    /// do.
+    pub fn locate(&self, file_name: &str) -> Option<PathBuf> {
+        self.dirs
+            .iter()
+            .map(|(_, dir)| dir.join(file_name))
+            .find(|path| path.is_file())
+    }
+
+    /// Find `file_name` in the first directory that has it.
+    pub fn find(&self, file_name: &str) -> Result<PathBuf> {
+        if let Some(path) = self.locate(file_name) {
+            return Ok(path);
+        }
+        let mut message = format!("couldn't find input {}, looked in:", file_name);
+        for (origin, dir) in &self.dirs {
+            write!(
+                message,
+                "\n  {} ({})",
+                dir.join(file_name).display(),
+                origin
+            )?;
+        }
+        Err(eyre!(message))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+    use crate::stand_in::TempDir;
+    use std::fs::{create_dir_all, write};
+
+    const LAYOUT: Layout = Layout {
+        year: 2021,
+        dir: "/package/src/data",
+        input: |day| format!("day{}", day),
+        sample: |day, n| format!("day{}-sample{}", day, n),
+        source: Source {
+            dir: "/package/src",
+            module: |day| format!("days/day{}.rs", day),
+            declarations: "days/mod.rs",
+        },
+    };
+
+    fn config(root: &str) -> Config {
+        Config {
+            data_dir: Some(PathBuf::from(root)),
+            path: Some(PathBuf::from("/home/aoc.toml")),
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn puts_each_source_in_priority_order() {
+        let flag = Path::new("/flag");
+        let env = || Some(OsString::from("/env"));
+        let primary = |flag, env, config: &Config| {
+            DataDirs::from_sources(flag, env, config, &LAYOUT)
+                .primary()
+                .to_path_buf()
+        };
+
+        let everything = DataDirs::from_sources(Some(flag), env(), &config("/toml"), &LAYOUT);
+        let dirs: Vec<_> = everything
+            .dirs
+            .iter()
+            .map(|(_, dir)| dir.as_path())
+            .collect();
+        assert_eq!(
+            dirs,
+            [
+                Path::new("/flag/2021"),
+                Path::new("/env/2021"),
+                Path::new("/toml/2021"),
+                Path::new("/package/src/data"),
+            ]
+        );
+        assert_eq!(
+            primary(None, env(), &config("/toml")),
+            Path::new("/env/2021")
+        );
+        assert_eq!(
+            primary(None, None, &config("/toml")),
+            Path::new("/toml/2021")
+        );
+        assert_eq!(
+            primary(None, None, &Config::default()),
+            Path::new("/package/src/data")
+        );
+    }
+
+    #[test]
+    fn finds_inputs_in_the_first_dir_that_has_them() {
+        let root = TempDir::new("data-find");
+        for dir in ["flag/2021", "env/2021"] {
+            create_dir_all(root.join(dir)).unwrap();
+        }
+        write(root.join("flag/2021/day1"), "1\n").unwrap();
+        write(root.join("env/2021/day1"), "2\n").unwrap();
+        write(root.join("env/2021/day2"), "3\n").unwrap();
+        let dirs = DataDirs::from_sources(
+            Some(&root.join("flag")),
+            Some(root.join("env").into()),
+            &Config::default(),
+            &LAYOUT,
+        );
+
+        assert_eq!(dirs.find("day1").unwrap(), root.join("flag/2021/day1"));
+        assert_eq!(dirs.find("day2").unwrap(), root.join("env/2021/day2"));
+        let missing = dirs.find("day3").unwrap_err().to_string();
+        assert_eq!(
+            missing,
+            format!(
+                "couldn't find input day3, looked in:\n  {} (--data-dir)\n  {} ($AOC_DATA_DIR)\n  \
+                 /package/src/data/day3 (package default)",
+                root.join("flag/2021/day3").display(),
+                root.join("env/2021/day3").display(),
+            )
+        );
+    }
+}
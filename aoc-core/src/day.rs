@@ -0,0 +1,67 @@
+use crate::answer::Answer;
+use eyre::{eyre, Report, Result};
+use std::fmt;
+
+/// One half of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// The part's number, 1 or 2.
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    /// The part numbered `n`, if there is one.
+    pub fn from_number(n: u8) -> Option<Part> {
+        Part::ALL.into_iter().find(|part| part.number() == n)
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "part 1"),
+            Part::Two => write!(f, "part 2"),
+        }
+    }
+}
+
+/// The error for a [`Day::configure`] key the day doesn't know.
+pub fn no_such_option(key: &str) -> Report {
+    eyre!("this day has no {:?} option", key)
+}
+
+/// A single day's solution.
+///
+/// The raw input is parsed once into [`Day::Input`] and then handed to each
+/// part, which lets the runner time parsing separately from solving.
+pub trait Day {
+    /// The parsed puzzle input shared by both parts.
+    type Input<'a>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>>;
+    fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer>;
+    fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer>;
+
+    /// The samples to run a part against before the real input. Most days
+    /// only have the one.
+    fn samples(&self, _part: Part) -> &'static [u8] {
+        &[1]
+    }
+
+    /// Set one of the day's own options before it runs, such as the rule
+    /// a cellular automaton day simulates. Days without options reject
+    /// every key.
+    fn configure(&mut self, key: &str, _value: &str) -> Result<()> {
+        Err(no_such_option(key))
+    }
+}
@@ -0,0 +1,353 @@
+use crate::answers::Expected;
+use crate::day::Part;
+use crate::input::Layout;
+use eyre::{eyre, Result, WrapErr};
+use std::fs::{read_to_string, write};
+use std::path::{Path, PathBuf};
+
+/// An example from a puzzle description, with the answers the description
+/// gives for it.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Example {
+    pub text: String,
+    pub expected: Expected,
+}
+
+/// Pull the examples out of a saved puzzle page.
+///
+/// Each part's description is an `<article>`. Every `<pre><code>` block
+/// in it is an example input, numbered in page order, and the last
+/// emphasized `<code><em>` before the next block is its answer. When part
+/// two's description repeats an earlier example, its answer goes with that
+/// one, and when it has no example of its own, with the last one.
+pub fn examples(html: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = vec![];
+    for (article, part) in articles(html).into_iter().zip(Part::ALL) {
+        let blocks = blocks(article);
+        if blocks.is_empty() {
+            if let (Some(example), Some(answer)) = (examples.last_mut(), last_answer(article)) {
+                example.expected.set(part, answer);
+            }
+            continue;
+        }
+        for (block, after) in blocks {
+            let block = text(block);
+            let i = match examples.iter().position(|e| e.text == block) {
+                Some(i) => i,
+                None => {
+                    examples.push(Example {
+                        text: block,
+                        ..Example::default()
+                    });
+                    examples.len() - 1
+                }
+            };
+            if let Some(answer) = last_answer(after) {
+                examples[i].expected.set(part, answer);
+            }
+        }
+    }
+    examples
+}
+
+/// The inside of each `<article>` on the page, in order.
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = vec![];
+    let mut rest = html;
+    while let Some((_, after)) = rest.split_once("<article") {
+        let Some((_, body)) = after.split_once('>') else {
+            break;
+        };
+        let (article, after) = body.split_once("</article>").unwrap_or((body, ""));
+        articles.push(article);
+        rest = after;
+    }
+    articles
+}
+
+/// The inside of each `<pre><code>` block in an article, along with the
+/// text from there to the next block, which is where its answer is given.
+fn blocks(article: &str) -> Vec<(&str, &str)> {
+    let mut blocks = vec![];
+    let mut rest = article;
+    while let Some((_, after)) = rest.split_once("<pre><code>") {
+        let (block, after) = after.split_once("</code></pre>").unwrap_or((after, ""));
+        let until_next = after
+            .split_once("<pre><code>")
+            .map_or(after, |(text, _)| text);
+        blocks.push((block, until_next));
+        rest = after;
+    }
+    blocks
+}
+
+/// The last emphasized code in an article, which is where the puzzle
+/// gives the example's answer. It's written both `<code><em>` and
+/// `<em><code>`.
+fn last_answer(article: &str) -> Option<String> {
+    ["<code><em>", "<em><code>"]
+        .iter()
+        .filter_map(|open| {
+            let at = article.rfind(open)?;
+            let inside = &article[at + open.len()..];
+            let end = inside.find('<')?;
+            Some((at, text(&inside[..end])))
+        })
+        .max_by_key(|(at, _)| *at)
+        .map(|(_, answer)| answer)
+        .filter(|answer| !answer.is_empty())
+}
+
+/// Strip tags from `html` and decode its entities.
+fn text(html: &str) -> String {
+    let mut stripped = String::new();
+    let mut in_tag = false;
+    for ch in html.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => stripped.push(ch),
+            _ => {}
+        }
+    }
+
+    let mut text = String::new();
+    let mut rest = stripped.as_str();
+    while let Some(at) = rest.find('&') {
+        text.push_str(&rest[..at]);
+        rest = &rest[at..];
+        let decoded = rest.find(';').and_then(|end| {
+            let ch = match &rest[1..end] {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                entity => {
+                    let code = match entity.strip_prefix("#x") {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => entity.strip_prefix('#')?.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((ch, end + 1))
+        });
+        match decoded {
+            Some((ch, len)) => {
+                text.push(ch);
+                rest = &rest[len..];
+            }
+            None => {
+                text.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    text.push_str(rest);
+    text
+}
+
+/// What happened to a sample file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Written {
+    Sample(PathBuf),
+    /// The sample was already there with the same text.
+    Unchanged(PathBuf),
+    Expected(PathBuf),
+}
+
+/// Write the examples from the saved puzzle page at `html` as `day`'s
+/// numbered samples in the package's data directory, each with an
+/// expected-answer sidecar. Refuses to replace a sample that says
+/// something different unless `force` is set; empty placeholders left by
+/// `new` are filled in.
+pub fn write_samples(layout: &Layout, day: u8, html: &Path, force: bool) -> Result<Vec<Written>> {
+    let page = read_to_string(html).wrap_err_with(|| format!("reading {}", html.display()))?;
+    let examples = examples(&page);
+    if examples.is_empty() {
+        return Err(eyre!("found no examples in {}", html.display()));
+    }
+
+    let dir = Path::new(layout.dir);
+    let paths: Vec<PathBuf> = (1..=examples.len())
+        .map(|n| dir.join((layout.sample)(day, n as u8)))
+        .collect();
+    for (example, path) in examples.iter().zip(&paths) {
+        match read_to_string(path) {
+            Ok(existing) if !existing.is_empty() && existing != example.text && !force => {
+                return Err(eyre!(
+                    "{} already exists with a different example, use --force to replace it",
+                    path.display()
+                ))
+            }
+            _ => {}
+        }
+    }
+
+    let mut written = vec![];
+    for (example, path) in examples.iter().zip(paths) {
+        let sidecar = Expected::path(&path);
+        let mut expected = example.expected.clone();
+        if read_to_string(&path).is_ok_and(|existing| existing == example.text) {
+            // keep answers from a page saved before the second part was
+            // solved, as long as the sample itself hasn't changed.
+            if let Ok(existing) = Expected::load(&sidecar) {
+                expected.part1 = expected.part1.or(existing.part1);
+                expected.part2 = expected.part2.or(existing.part2);
+            }
+            written.push(Written::Unchanged(path.clone()));
+        } else {
+            write(&path, &example.text).wrap_err_with(|| format!("writing {}", path.display()))?;
+            written.push(Written::Sample(path.clone()));
+        }
+        expected.save(&sidecar)?;
+        written.push(Written::Expected(sidecar));
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+    use crate::scaffold;
+    use crate::stand_in::TempDir;
+    use std::fs::create_dir_all;
+
+    /// Trimmed down from a saved puzzle page.
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<head><title>Day 1 - Advent of Code 2023</title></head>
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 1: Trebuchet?! ---</h2><p>For example:</p>
+<pre><code>1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet
+</code></pre>
+<p>In this example, the calibration values of these four lines are <code>12</code>, <code>38</code>, <code>15</code>, and <code>77</code>. Adding these together produces <code><em>142</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>55712</code>.</p><article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Equipped with this new information, you now need to find the real first and last digit on each line. For example:</p>
+<pre><code>two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+</code></pre>
+<p>In this example, the calibration values are <code>29</code>, <code>83</code>, <code>13</code>, <code>24</code>, <code>42</code>, <code>14</code>, and <code>76</code>. Adding these together produces <code><em>281</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>55413</code>.</p>
+</main>
+</body>
+</html>
+"#;
+
+    #[test]
+    fn pulls_an_example_per_part() {
+        let examples = examples(PAGE);
+
+        assert_eq!(examples.len(), 2);
+        assert!(examples[0].text.starts_with("1abc2\npqr3stu8vwx\n"));
+        assert_eq!(examples[0].expected.get(Part::One), Some("142"));
+        assert_eq!(examples[0].expected.get(Part::Two), None);
+        assert!(examples[1].text.starts_with("two1nine\n"));
+        assert_eq!(examples[1].expected.get(Part::One), None);
+        assert_eq!(examples[1].expected.get(Part::Two), Some("281"));
+    }
+
+    #[test]
+    fn reuses_the_example_when_part_two_has_none() {
+        let page = r#"
+<article class="day-desc"><pre><code>3   4
+4   3
+</code></pre><p>a total distance of <em><code>11</code></em>!</p></article>
+<article class="day-desc"><p>So, for these example lists, the similarity score at the end of this process is <code><em>31</em></code>.</p></article>
+"#;
+        let examples = examples(page);
+
+        assert_eq!(examples.len(), 1);
+        assert_eq!(examples[0].text, "3   4\n4   3\n");
+        assert_eq!(examples[0].expected.get(Part::One), Some("11"));
+        assert_eq!(examples[0].expected.get(Part::Two), Some("31"));
+    }
+
+    #[test]
+    fn pulls_every_example_in_an_article() {
+        let page = r#"
+<article class="day-desc"><pre><code>start-A
+A-end
+</code></pre><p>this cave system has <code><em>10</em></code> paths.</p>
+<p>Here is a slightly larger example:</p><pre><code>dc-end
+HN-start
+</code></pre><p>has <code><em>19</em></code> paths through it.</p></article>
+<article class="day-desc"><pre><code>start-A
+A-end
+</code></pre><p>now <code><em>36</em></code> paths.</p>
+<pre><code>kj-sa
+</code></pre><p>and this one has <code><em>3509</em></code>.</p></article>
+"#;
+        let examples = examples(page);
+
+        let texts: Vec<_> = examples.iter().map(|e| e.text.as_str()).collect();
+        assert_eq!(texts, ["start-A\nA-end\n", "dc-end\nHN-start\n", "kj-sa\n"]);
+        let answers: Vec<_> = examples
+            .iter()
+            .map(|e| (e.expected.get(Part::One), e.expected.get(Part::Two)))
+            .collect();
+        assert_eq!(
+            answers,
+            [
+                (Some("10"), Some("36")),
+                (Some("19"), None),
+                (None, Some("3509"))
+            ]
+        );
+    }
+
+    #[test]
+    fn fills_in_the_placeholders_from_new() {
+        let root = TempDir::new("extract-new");
+        let leak = |path: PathBuf| -> &'static str { Box::leak(path.display().to_string().into()) };
+        let layout = Layout {
+            year: 2015,
+            dir: leak(root.join("data")),
+            input: |day| format!("day{}", day),
+            sample: |day, n| format!("day{}-sample{}", day, n),
+            source: Source {
+                dir: leak(root.join("src")),
+                module: |day| format!("day{}.rs", day),
+                declarations: "main.rs",
+            },
+        };
+        create_dir_all(root.join("src")).unwrap();
+        write(root.join("src/main.rs"), "").unwrap();
+        scaffold::new_day(&layout, 1).unwrap();
+        let html = root.join("page.html");
+        write(&html, PAGE).unwrap();
+
+        let written = write_samples(&layout, 1, &html, false).unwrap();
+
+        let sample = root.join("data/day1-sample1");
+        assert_eq!(written[0], Written::Sample(sample.clone()));
+        assert!(read_to_string(&sample).unwrap().starts_with("1abc2\n"));
+        let expected = Expected::load(&Expected::path(&sample)).unwrap();
+        assert_eq!(expected.get(Part::One), Some("142"));
+
+        // a real sample is still only replaced with --force
+        write(&sample, "1\n").unwrap();
+        assert!(write_samples(&layout, 1, &html, false).is_err());
+        assert!(write_samples(&layout, 1, &html, true).is_ok());
+    }
+
+    #[test]
+    fn decodes_entities_and_strips_emphasis() {
+        assert_eq!(
+            text("&lt;x=1&gt; &amp; <em>#</em>.# &#39;a&#x27; &bogus"),
+            "<x=1> & #.# 'a' &bogus"
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use crate::config::Config;
+use crate::data::DataDirs;
+use crate::input::Layout;
+use eyre::{eyre, Result, WrapErr};
+use std::fs::{create_dir_all, metadata, rename, write};
+use std::path::PathBuf;
+
+/// Environment variable holding the session cookie used to talk to the
+/// puzzle server.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Identifies us to the puzzle server, as its maintainer asks automated
+/// tools to.
+const USER_AGENT: &str = "github.com/after-ephemera/aoc";
+
+/// A client for the puzzle server, authenticated with a session cookie.
+pub struct Client {
+    base_url: String,
+    session: Option<String>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub const BASE_URL: &'static str = "https://adventofcode.com";
+
+    pub fn new(base_url: impl Into<String>, session: Option<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// A client for the real server, taking the session from
+    /// `$AOC_SESSION` or failing that `session` in `aoc.toml`. Without
+    /// either, requests fail but the client can still be created.
+    pub fn from_config(config: &Config) -> Self {
+        let session = std::env::var(SESSION_VAR)
+            .ok()
+            .or_else(|| config.session.clone())
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty());
+        Self::new(Self::BASE_URL, session)
+    }
+
+    /// Build a request for `path`, which starts with a slash, carrying the
+    /// session cookie.
+    pub(crate) fn request(&self, method: &str, path: &str) -> Result<ureq::Request> {
+        let session = self.session.as_ref().ok_or_else(|| {
+            eyre!(
+                "no session token, set ${} or `session` in {}",
+                SESSION_VAR,
+                Config::FILE_NAME
+            )
+        })?;
+        Ok(self
+            .agent
+            .request(method, &format!("{}{}", self.base_url, path))
+            .set("Cookie", &format!("session={}", session)))
+    }
+
+    /// Download the real input for `day` of `year`.
+    pub fn input(&self, year: u16, day: u8) -> Result<String> {
+        let path = format!("/{}/day/{}/input", year, day);
+        match self.request("GET", &path)?.call() {
+            Ok(response) => response
+                .into_string()
+                .wrap_err_with(|| format!("reading {}", path)),
+            Err(ureq::Error::Status(400, _)) => Err(eyre!(
+                "the server rejected the session token fetching {}, it may have expired",
+                path
+            )),
+            Err(ureq::Error::Status(404, _)) => Err(eyre!(
+                "{} day {} isn't available yet, or doesn't exist",
+                year,
+                day
+            )),
+            Err(e) => Err(eyre!(e)).wrap_err_with(|| format!("fetching {}", path)),
+        }
+    }
+}
+
+/// Where a day's input came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// It was already in one of the data directories.
+    Cached(PathBuf),
+    /// It was downloaded into the highest priority data directory.
+    Downloaded(PathBuf),
+}
+
+impl Fetched {
+    pub fn path(&self) -> &PathBuf {
+        match self {
+            Fetched::Cached(path) | Fetched::Downloaded(path) => path,
+        }
+    }
+}
+
+/// Make sure `day`'s real input is on disk. An input already in any of
+/// `dirs` is never downloaded again, unless it's an empty placeholder left
+/// by `new`, which gets filled in where it is.
+pub fn input(client: &Client, layout: &Layout, dirs: &DataDirs, day: u8) -> Result<Fetched> {
+    let file_name = (layout.input)(day);
+    let placeholder = match dirs.locate(&file_name) {
+        Some(path) if metadata(&path).is_ok_and(|m| m.len() > 0) => {
+            return Ok(Fetched::Cached(path))
+        }
+        found => found,
+    };
+
+    let text = client.input(layout.year, day)?;
+    let path = placeholder.unwrap_or_else(|| dirs.primary().join(&file_name));
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent).wrap_err_with(|| format!("creating {}", parent.display()))?;
+    }
+    // write to the side first so an interrupted download never looks cached.
+    let partial = path.with_extension("partial");
+    write(&partial, text).wrap_err_with(|| format!("writing {}", partial.display()))?;
+    rename(&partial, &path).wrap_err_with(|| format!("writing {}", path.display()))?;
+    Ok(Fetched::Downloaded(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::input::Source;
+    use crate::stand_in::{serve, TempDir};
+    use std::fs::read_to_string;
+    use std::path::Path;
+
+    const LAYOUT: Layout = Layout {
+        year: 2021,
+        dir: "/nonexistent",
+        input: |day| format!("day{}/input.txt", day),
+        sample: |day, _| format!("day{}/sample.txt", day),
+        source: Source {
+            dir: "/nonexistent",
+            module: |day| format!("day{}.rs", day),
+            declarations: "main.rs",
+        },
+    };
+
+    /// A fresh data directory root for one test.
+    fn data_root(name: &str) -> TempDir {
+        TempDir::new(&format!("fetch-{}", name))
+    }
+
+    fn dirs(root: &Path) -> DataDirs {
+        DataDirs::resolve(Some(root), &Config::default(), &LAYOUT)
+    }
+
+    #[test]
+    fn downloads_into_the_data_dir_with_the_session() {
+        let root = data_root("download");
+        let (url, server) = serve(vec![(200, "1\n2\n3\n".to_string())]);
+        let client = Client::new(url, Some("abc123".to_string()));
+
+        let fetched = input(&client, &LAYOUT, &dirs(&root), 7).unwrap();
+
+        let expected = root.join("2021/day7/input.txt");
+        assert_eq!(fetched, Fetched::Downloaded(expected.clone()));
+        assert_eq!(read_to_string(expected).unwrap(), "1\n2\n3\n");
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].line, "GET /2021/day/7/input HTTP/1.1");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+        assert_eq!(requests[0].header("user-agent"), Some(USER_AGENT));
+    }
+
+    #[test]
+    fn never_downloads_a_cached_input_again() {
+        let root = data_root("cached");
+        let (url, server) = serve(vec![(200, "fresh".to_string())]);
+        let client = Client::new(url, Some("abc123".to_string()));
+
+        let first = input(&client, &LAYOUT, &dirs(&root), 1).unwrap();
+        let second = input(&client, &LAYOUT, &dirs(&root), 1).unwrap();
+
+        assert!(matches!(first, Fetched::Downloaded(_)));
+        assert_eq!(second, Fetched::Cached(first.path().clone()));
+        // the stand-in stops listening after one response, so a second
+        // download would have failed.
+        assert_eq!(server.join().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn fills_in_an_empty_placeholder() {
+        let root = data_root("placeholder");
+        let placeholder = root.join("2021/day4/input.txt");
+        create_dir_all(placeholder.parent().unwrap()).unwrap();
+        write(&placeholder, "").unwrap();
+        let (url, server) = serve(vec![(200, "7,4,9".to_string())]);
+        let client = Client::new(url, Some("abc123".to_string()));
+
+        let fetched = input(&client, &LAYOUT, &dirs(&root), 4).unwrap();
+
+        assert_eq!(fetched, Fetched::Downloaded(placeholder.clone()));
+        assert_eq!(read_to_string(placeholder).unwrap(), "7,4,9");
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn reports_a_rejected_session() {
+        let root = data_root("rejected");
+        let (url, server) = serve(vec![(400, "Puzzle inputs differ by user.".to_string())]);
+        let client = Client::new(url, Some("expired".to_string()));
+
+        let err = input(&client, &LAYOUT, &dirs(&root), 2).unwrap_err();
+
+        assert!(err.to_string().contains("rejected the session"), "{}", err);
+        assert!(!root.join("2021/day2/input.txt").exists());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn reports_a_locked_day() {
+        let root = data_root("locked");
+        let (url, server) = serve(vec![(404, "Not Found".to_string())]);
+        let client = Client::new(url, Some("abc123".to_string()));
+
+        let err = input(&client, &LAYOUT, &dirs(&root), 25).unwrap_err();
+
+        assert!(err.to_string().contains("isn't available yet"), "{}", err);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn needs_a_session_to_download() {
+        let root = data_root("no-session");
+        let client = Client::new("http://127.0.0.1:9", None);
+
+        let err = input(&client, &LAYOUT, &dirs(&root), 3).unwrap_err();
+
+        assert!(err.to_string().contains(SESSION_VAR), "{}", err);
+    }
+}
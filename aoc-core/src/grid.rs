@@ -0,0 +1,277 @@
+use crate::parse::ParseError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A position in a [`Grid`] as `(x, y)`, with `(0, 0)` at the top left.
+pub type Point = (usize, usize);
+
+/// A step between points as `(dx, dy)`.
+pub type Delta = (isize, isize);
+
+/// The steps to the four orthogonal neighbours, clockwise from up.
+pub const NEIGHBORS4: [Delta; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// The steps to all eight neighbours, clockwise from up.
+pub const NEIGHBORS8: [Delta; 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// Cells that can be read from a single character of puzzle input.
+pub trait FromChar: Sized {
+    /// What a cell looks like, for parse errors, e.g. "'.' or '#'".
+    const EXPECTED: &'static str;
+
+    fn from_char(c: char) -> Option<Self>;
+}
+
+impl FromChar for char {
+    const EXPECTED: &'static str = "a character";
+
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+}
+
+macro_rules! from_char_digit {
+    ($($t:ty),*) => {
+        $(
+            impl FromChar for $t {
+                const EXPECTED: &'static str = "a digit 0-9";
+
+                fn from_char(c: char) -> Option<Self> {
+                    c.to_digit(10).map(|d| d as $t)
+                }
+            }
+        )*
+    };
+}
+
+from_char_digit!(u8, u32, usize);
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parse one row per line and one cell per character. Trailing blank
+    /// lines are ignored, but every row has to be the same width.
+    pub fn parse(input: &str) -> Result<Self, ParseError>
+    where
+        T: FromChar,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for line in input.trim_end_matches(['\n', '\r']).lines() {
+            let row_start = cells.len();
+            for (i, c) in line.char_indices() {
+                let cell = T::from_char(c).ok_or_else(|| {
+                    ParseError::at(input, &line[i..i + c.len_utf8()], T::EXPECTED)
+                })?;
+                cells.push(cell);
+            }
+            let row_width = cells.len() - row_start;
+            match width {
+                Some(width) if width != row_width => {
+                    let expected = format!("a row {} wide", width);
+                    return Err(ParseError::at(input, line, expected));
+                }
+                _ => width = Some(row_width),
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Grid {
+                width,
+                height,
+                cells,
+            }),
+            _ => Err(ParseError::end(input, "a grid")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Point) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): Point) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// The point `delta` away from `point`, if it's on the grid.
+    pub fn offset(&self, (x, y): Point, (dx, dy): Delta) -> Option<Point> {
+        let x = x.checked_add_signed(dx).filter(|&x| x < self.width)?;
+        let y = y.checked_add_signed(dy).filter(|&y| y < self.height)?;
+        Some((x, y))
+    }
+
+    /// The orthogonal neighbours of `point` that are on the grid.
+    pub fn neighbors4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBORS4
+            .iter()
+            .filter_map(move |&delta| self.offset(point, delta))
+    }
+
+    /// All neighbours of `point`, diagonals included, that are on the grid.
+    pub fn neighbors8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        NEIGHBORS8
+            .iter()
+            .filter_map(move |&delta| self.offset(point, delta))
+    }
+
+    /// The points passed stepping `delta` at a time from `from`, not
+    /// including `from` itself, until the edge of the grid.
+    pub fn ray(&self, from: Point, delta: Delta) -> impl Iterator<Item = Point> + '_ {
+        std::iter::successors(self.offset(from, delta), move |&point| {
+            self.offset(point, delta)
+        })
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// Every point on the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell along with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Every cell, row by row.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{:?} is off the grid", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{:?} is off the grid", point))
+    }
+}
+
+/// One line per row, with each cell's own `Display` written in turn.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "123\n456\n789\n\n";
+
+    #[test]
+    fn parses_and_displays() {
+        let grid = Grid::<u32>::parse(INPUT).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![2, 5, 8]);
+        assert_eq!(grid.to_string(), "123\n456\n789\n");
+    }
+
+    #[test]
+    fn reports_bad_cells_and_ragged_rows() {
+        let error = Grid::<u32>::parse("12\n3x\n").unwrap_err();
+        assert_eq!(error.to_string(), r#"2:2: expected a digit 0-9, found "x""#);
+
+        let error = Grid::<char>::parse("ab\nc\n").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            r#"2:1: expected a row 2 wide, found "c""#
+        );
+
+        assert!(Grid::<char>::parse("\n").is_err());
+    }
+
+    #[test]
+    fn stays_in_bounds() {
+        let grid = Grid::<u32>::parse(INPUT).unwrap();
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+        assert_eq!(grid.neighbors8((2, 2)).count(), 3);
+        assert_eq!(
+            grid.ray((0, 0), (1, 1)).collect::<Vec<_>>(),
+            [(1, 1), (2, 2)]
+        );
+        assert_eq!(grid.ray((0, 0), (-1, 0)).count(), 0);
+    }
+}
@@ -0,0 +1,219 @@
+use crate::parse::ParseError;
+use std::fmt;
+use std::ops::{Add, Neg, Sub};
+
+/// One of the six ways out of a hexagon on a floor of hexagons with
+/// pointy tops, so rows run east to west.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexDirection {
+    E,
+    SE,
+    SW,
+    W,
+    NW,
+    NE,
+}
+
+impl HexDirection {
+    /// Every direction, clockwise from east.
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::E,
+        HexDirection::SE,
+        HexDirection::SW,
+        HexDirection::W,
+        HexDirection::NW,
+        HexDirection::NE,
+    ];
+
+    /// How the direction is written, e.g. "se".
+    pub fn name(self) -> &'static str {
+        match self {
+            HexDirection::E => "e",
+            HexDirection::SE => "se",
+            HexDirection::SW => "sw",
+            HexDirection::W => "w",
+            HexDirection::NW => "nw",
+            HexDirection::NE => "ne",
+        }
+    }
+
+    /// The step to the neighbour in this direction.
+    pub fn delta(self) -> HexCoord {
+        match self {
+            HexDirection::E => HexCoord::new(1, 0),
+            HexDirection::SE => HexCoord::new(0, 1),
+            HexDirection::SW => HexCoord::new(-1, 1),
+            HexDirection::W => HexCoord::new(-1, 0),
+            HexDirection::NW => HexCoord::new(0, -1),
+            HexDirection::NE => HexCoord::new(1, -1),
+        }
+    }
+
+    /// The direction `turns` sixths of a turn clockwise, or anticlockwise
+    /// if negative.
+    pub fn turn(self, turns: isize) -> HexDirection {
+        let index = Self::ALL.iter().position(|&d| d == self).unwrap() as isize;
+        Self::ALL[(index + turns).rem_euclid(6) as usize]
+    }
+
+    /// Parse directions written back to back with no separators, like
+    /// "esenee", from `path`, a slice of `input`.
+    pub fn parse_path(input: &str, path: &str) -> Result<Vec<HexDirection>, ParseError> {
+        let mut directions = vec![];
+        let mut rest = path;
+        while !rest.is_empty() {
+            let direction = Self::ALL
+                .iter()
+                .find(|d| rest.starts_with(d.name()))
+                .ok_or_else(|| {
+                    let len = rest.chars().next().map_or(0, char::len_utf8);
+                    ParseError::at(input, &rest[..len], "one of e, se, sw, w, nw or ne")
+                })?;
+            directions.push(*direction);
+            rest = &rest[direction.name().len()..];
+        }
+        Ok(directions)
+    }
+}
+
+impl fmt::Display for HexDirection {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// A hexagon's position in axial coordinates, where `q` counts east and
+/// `r` counts south-east. The third cube coordinate, `s`, follows from
+/// them since `q + r + s` is always 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct HexCoord {
+    pub q: isize,
+    pub r: isize,
+}
+
+impl HexCoord {
+    pub const ORIGIN: HexCoord = HexCoord { q: 0, r: 0 };
+
+    pub const fn new(q: isize, r: isize) -> Self {
+        HexCoord { q, r }
+    }
+
+    pub fn s(self) -> isize {
+        -self.q - self.r
+    }
+
+    /// The position as cube coordinates `(q, r, s)`.
+    pub fn cube(self) -> (isize, isize, isize) {
+        (self.q, self.r, self.s())
+    }
+
+    pub fn step(self, direction: HexDirection) -> Self {
+        self + direction.delta()
+    }
+
+    /// Where following `path` from here ends up.
+    pub fn walk(self, path: &[HexDirection]) -> Self {
+        path.iter().fold(self, |at, &direction| at.step(direction))
+    }
+
+    /// The six neighbours, clockwise from east.
+    pub fn neighbors(self) -> [HexCoord; 6] {
+        HexDirection::ALL.map(|direction| self.step(direction))
+    }
+
+    /// How many steps it takes to get to `other`.
+    pub fn distance(self, other: HexCoord) -> usize {
+        let (q, r, s) = (other - self).cube();
+        q.unsigned_abs().max(r.unsigned_abs()).max(s.unsigned_abs())
+    }
+
+    /// The position `turns` sixths of a turn clockwise around `center`, or
+    /// anticlockwise if negative.
+    pub fn rotate(self, center: HexCoord, turns: isize) -> Self {
+        let (mut q, mut r, mut s) = (self - center).cube();
+        for _ in 0..turns.rem_euclid(6) {
+            (q, r, s) = (-r, -s, -q);
+        }
+        debug_assert_eq!(q + r + s, 0);
+        center + HexCoord::new(q, r)
+    }
+}
+
+impl Add for HexCoord {
+    type Output = HexCoord;
+
+    fn add(self, other: HexCoord) -> HexCoord {
+        HexCoord::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Sub for HexCoord {
+    type Output = HexCoord;
+
+    fn sub(self, other: HexCoord) -> HexCoord {
+        HexCoord::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl Neg for HexCoord {
+    type Output = HexCoord;
+
+    fn neg(self) -> HexCoord {
+        HexCoord::new(-self.q, -self.r)
+    }
+}
+
+impl fmt::Display for HexCoord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.q, self.r)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use HexDirection::*;
+
+    #[test]
+    fn parses_paths() {
+        let input = "esenee\nnwwswee\nnex\n";
+        let path = HexDirection::parse_path(input, &input[..6]).unwrap();
+        assert_eq!(path, [E, SE, NE, E]);
+        assert_eq!(HexCoord::ORIGIN.walk(&path), HexCoord::new(3, 0));
+
+        // "nwwswee" goes back to where it started
+        let path = HexDirection::parse_path(input, &input[7..14]).unwrap();
+        assert_eq!(HexCoord::ORIGIN.walk(&path), HexCoord::ORIGIN);
+
+        let error = HexDirection::parse_path(input, &input[15..18]).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (3, 3, "x"));
+    }
+
+    #[test]
+    fn neighbors_are_one_step_away() {
+        let center = HexCoord::new(2, -5);
+        for neighbor in center.neighbors() {
+            assert_eq!(center.distance(neighbor), 1);
+            assert!(neighbor.neighbors().contains(&center));
+        }
+        assert_eq!(
+            HexCoord::ORIGIN
+                .walk(&[E, E, NE, NE])
+                .distance(HexCoord::ORIGIN),
+            4
+        );
+        assert_eq!(HexCoord::ORIGIN.walk(&[E, W, SE, NW]), HexCoord::ORIGIN);
+    }
+
+    #[test]
+    fn rotates() {
+        let center = HexCoord::new(1, 1);
+        for direction in HexDirection::ALL {
+            let from = center.step(direction);
+            assert_eq!(from.rotate(center, 1), center.step(direction.turn(1)));
+            assert_eq!(from.rotate(center, -2), center.step(direction.turn(-2)));
+            assert_eq!(from.rotate(center, 6), from);
+        }
+        assert_eq!(E.turn(3), W);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::data::DataDirs;
+use crate::day::Part;
+use eyre::{Result, WrapErr};
+use std::cell::OnceCell;
+use std::fs::read_to_string;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Describes where a year keeps its puzzle inputs and how they are named.
+pub struct Layout {
+    pub year: u16,
+    /// Directory holding the input files when no other data directory is
+    /// configured. Should be absolute, e.g. built from `CARGO_MANIFEST_DIR`.
+    pub dir: &'static str,
+    /// File name of a day's real input.
+    pub input: fn(u8) -> String,
+    /// File name of a day's nth sample, counting from 1.
+    pub sample: fn(u8, u8) -> String,
+    pub source: Source,
+}
+
+/// Describes where a year keeps its solutions, so new days can be
+/// scaffolded.
+pub struct Source {
+    /// The package's `src` directory. Should be absolute.
+    pub dir: &'static str,
+    /// Path of a day's module, relative to `dir`.
+    pub module: fn(u8) -> String,
+    /// File declaring the days' modules, relative to `dir`.
+    pub declarations: &'static str,
+}
+
+/// Which input(s) a day gets run against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selection {
+    /// Each part's samples followed by the real input.
+    All,
+    /// Only the nth sample, counting from 1.
+    Sample(u8),
+    /// A file outside the year's layout.
+    File(PathBuf),
+    /// Whatever is piped in on stdin.
+    Stdin,
+}
+
+/// A named puzzle input.
+#[derive(Debug, Clone)]
+pub struct Input {
+    /// What the input is called when reporting on it, e.g. "sample 2".
+    pub name: String,
+    pub text: String,
+}
+
+/// The single place days get their input text from, so that no day opens
+/// files itself.
+pub struct InputProvider<'a> {
+    layout: &'a Layout,
+    dirs: DataDirs,
+    day: u8,
+    selection: Selection,
+    // stdin can only be read once, so keep it around for the second part.
+    stdin: OnceCell<String>,
+}
+
+impl<'a> InputProvider<'a> {
+    pub fn new(layout: &'a Layout, dirs: DataDirs, day: u8, selection: Selection) -> Self {
+        Self {
+            layout,
+            dirs,
+            day,
+            selection,
+            stdin: OnceCell::new(),
+        }
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub fn selection(&self) -> &Selection {
+        &self.selection
+    }
+
+    /// The inputs `part` should be run against, given the samples the day
+    /// has for it.
+    pub fn inputs(&self, part: Part, samples: &[u8]) -> Result<Vec<Input>> {
+        match &self.selection {
+            Selection::All => {
+                let mut inputs = samples
+                    .iter()
+                    .map(|&n| self.sample(n))
+                    .collect::<Result<Vec<_>>>()?;
+                inputs.push(self.real()?);
+                Ok(inputs)
+            }
+            Selection::Sample(n) => Ok(vec![self.sample(*n)?]),
+            Selection::File(path) => Ok(vec![Input {
+                name: path.display().to_string(),
+                text: load(path)?,
+            }]),
+            Selection::Stdin => {
+                let text = match self.stdin.get() {
+                    Some(text) => text.clone(),
+                    None => {
+                        let mut text = String::new();
+                        std::io::stdin()
+                            .read_to_string(&mut text)
+                            .wrap_err_with(|| format!("reading {} input from stdin", part))?;
+                        self.stdin.get_or_init(|| text).clone()
+                    }
+                };
+                Ok(vec![Input {
+                    name: "stdin".to_string(),
+                    text,
+                }])
+            }
+        }
+    }
+
+    /// The real puzzle input.
+    pub fn real(&self) -> Result<Input> {
+        Ok(Input {
+            name: "input".to_string(),
+            text: load(self.dirs.find(&(self.layout.input)(self.day))?)?,
+        })
+    }
+
+    /// The nth sample input, counting from 1.
+    pub fn sample(&self, n: u8) -> Result<Input> {
+        Ok(Input {
+            name: match n {
+                1 => "sample".to_string(),
+                _ => format!("sample {}", n),
+            },
+            text: load(self.dirs.find(&(self.layout.sample)(self.day, n))?)?,
+        })
+    }
+}
+
+/// Read an input file, naming the file in the error if that fails.
+pub fn load(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    read_to_string(path).wrap_err_with(|| format!("reading input {}", path.display()))
+}
@@ -0,0 +1,178 @@
+use std::cmp::{max, min};
+use std::fmt;
+use std::ops::Range;
+
+/// A set of integers stored as the half-open ranges it covers, for when
+/// there are far too many to list one by one.
+///
+/// The ranges are kept sorted, non-empty, and neither overlapping nor
+/// touching, so two sets covering the same integers compare equal.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct IntervalSet {
+    ranges: Vec<Range<isize>>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The sorted, disjoint ranges making up the set.
+    pub fn ranges(&self) -> &[Range<isize>] {
+        &self.ranges
+    }
+
+    /// How many integers are in the set.
+    pub fn len(&self) -> usize {
+        self.ranges.iter().map(|r| r.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: isize) -> bool {
+        let after = self.ranges.partition_point(|r| r.start <= value);
+        after > 0 && self.ranges[after - 1].contains(&value)
+    }
+
+    pub fn min(&self) -> Option<isize> {
+        self.ranges.first().map(|r| r.start)
+    }
+
+    pub fn max(&self) -> Option<isize> {
+        self.ranges.last().map(|r| r.end - 1)
+    }
+
+    pub fn insert(&mut self, range: Range<isize>) {
+        *self = self.union(&IntervalSet::from(range));
+    }
+
+    /// Every integer in either set.
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    /// Every integer in both sets.
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = vec![];
+        let (mut i, mut j) = (0, 0);
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let overlap = max(a.start, b.start)..min(a.end, b.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            // move past whichever range ends first
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Every integer in this set but not `other`.
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut ranges = vec![];
+        let mut j = 0;
+        for range in &self.ranges {
+            let mut start = range.start;
+            // skip ranges of other that end before this one starts
+            while j < other.ranges.len() && other.ranges[j].end <= start {
+                j += 1;
+            }
+            let mut k = j;
+            while k < other.ranges.len() && other.ranges[k].start < range.end {
+                let hole = &other.ranges[k];
+                if hole.start > start {
+                    ranges.push(start..hole.start);
+                }
+                start = max(start, hole.end);
+                k += 1;
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// The set with `by` added to every integer.
+    pub fn shift(&self, by: isize) -> IntervalSet {
+        IntervalSet {
+            ranges: self
+                .ranges
+                .iter()
+                .map(|r| r.start + by..r.end + by)
+                .collect(),
+        }
+    }
+}
+
+impl From<Range<isize>> for IntervalSet {
+    fn from(range: Range<isize>) -> Self {
+        std::iter::once(range).collect()
+    }
+}
+
+impl FromIterator<Range<isize>> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Range<isize>>>(iter: I) -> Self {
+        let mut sorted: Vec<_> = iter.into_iter().filter(|r| !r.is_empty()).collect();
+        sorted.sort_by_key(|r| r.start);
+        let mut ranges: Vec<Range<isize>> = Vec::with_capacity(sorted.len());
+        for range in sorted {
+            match ranges.last_mut() {
+                Some(last) if range.start <= last.end => last.end = max(last.end, range.end),
+                _ => ranges.push(range),
+            }
+        }
+        IntervalSet { ranges }
+    }
+}
+
+impl fmt::Display for IntervalSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ranges: Vec<_> = self.ranges.iter().map(|r| format!("{:?}", r)).collect();
+        write!(f, "{{{}}}", ranges.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<isize>]) -> IntervalSet {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn merges_overlapping_and_touching_ranges() {
+        let merged = set(&[5..8, 0..2, 1..3, 3..4, 9..9]);
+        assert_eq!(merged.ranges(), &[0..4, 5..8]);
+        assert_eq!(merged.len(), 7);
+        assert!(merged.contains(3) && !merged.contains(4) && merged.contains(7));
+        assert_eq!((merged.min(), merged.max()), (Some(0), Some(7)));
+        assert_eq!(merged.to_string(), "{0..4, 5..8}");
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[0..10, 20..30]);
+        let b = set(&[5..25, 28..40]);
+        assert_eq!(a.union(&b), IntervalSet::from(0..40));
+        assert_eq!(a.intersection(&b), set(&[5..10, 20..25, 28..30]));
+        assert_eq!(a.difference(&b), set(&[0..5, 25..28]));
+        assert_eq!(b.difference(&a), set(&[10..20, 30..40]));
+        assert_eq!(a.difference(&a), IntervalSet::new());
+        assert_eq!(a.shift(-5), set(&[-5..5, 15..25]));
+    }
+
+    #[test]
+    fn difference_with_several_holes() {
+        let a = IntervalSet::from(0..100);
+        let holes = set(&[-5..1, 10..20, 30..40, 99..200]);
+        assert_eq!(a.difference(&holes), set(&[1..10, 20..30, 40..99]));
+    }
+}
@@ -0,0 +1,47 @@
+//! Shared plumbing for every year's solutions: the [`Day`] trait that
+//! solutions implement, the [`Answer`]s they produce and the recorded
+//! [`Answers`] they're checked against, loading of puzzle inputs, the
+//! registry of days, and the runner that ties them together. It also has
+//! helpers that puzzles share, like parsing, [`Grid`]s, interval sets,
+//! interned [`Symbol`]s and cellular automata.
+
+pub mod answer;
+pub mod answers;
+pub mod automaton;
+pub mod bench;
+pub mod cli;
+pub mod config;
+pub mod data;
+pub mod day;
+pub mod extract;
+pub mod fetch;
+pub mod grid;
+pub mod hex;
+pub mod input;
+pub mod interval;
+pub mod parse;
+pub mod registry;
+pub mod runner;
+pub mod sample_tests;
+pub mod scaffold;
+pub mod submit;
+pub mod symbol;
+
+#[cfg(test)]
+mod stand_in;
+
+pub use answer::Answer;
+pub use answers::{Answers, Expected};
+pub use cli::InputArgs;
+pub use config::Config;
+pub use data::DataDirs;
+pub use day::{Day, Part};
+pub use grid::{FromChar, Grid};
+pub use input::{Input, InputProvider, Layout, Selection, Source};
+pub use parse::ParseError;
+pub use registry::Registration;
+pub use runner::{run, Solution};
+pub use symbol::Symbol;
+
+#[doc(hidden)]
+pub use inventory;
@@ -0,0 +1,130 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// Where in an input a parser gave up, and on what.
+///
+/// Parsers only see the input's text, so they build these with a position
+/// in it and the runner fills in which input it was before reporting.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// What the input is called, e.g. "sample 2", once it's known.
+    pub input: Option<String>,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, counted in characters.
+    pub column: usize,
+    /// The offending text, empty if the input ran out.
+    pub text: String,
+    /// What the parser wanted instead, e.g. "a number".
+    pub expected: String,
+}
+
+impl ParseError {
+    /// An error at `text`, which should be a slice of `input`. Text that
+    /// isn't is looked for in `input` instead.
+    pub fn at(input: &str, text: &str, expected: impl Into<String>) -> Self {
+        let offset = offset_of(input, text)
+            .or_else(|| input.find(text))
+            .unwrap_or(input.len());
+        Self::at_offset(input, offset, text, expected)
+    }
+
+    /// An error just past `text`, a slice of `input`, for when something
+    /// that should follow it is missing.
+    pub fn after(input: &str, text: &str, expected: impl Into<String>) -> Self {
+        let offset = offset_of(input, text)
+            .or_else(|| input.find(text))
+            .map_or(input.len(), |at| at + text.len());
+        Self::at_offset(input, offset, "", expected)
+    }
+
+    /// An error at the end of `input`, for when it's cut short.
+    pub fn end(input: &str, expected: impl Into<String>) -> Self {
+        Self::at_offset(input, input.len(), "", expected)
+    }
+
+    /// The same error, naming the input it came from.
+    pub fn in_input(mut self, name: &str) -> Self {
+        self.input = Some(name.to_string());
+        self
+    }
+
+    fn at_offset(input: &str, offset: usize, text: &str, expected: impl Into<String>) -> Self {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |at| at + 1);
+        ParseError {
+            input: None,
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: text.to_string(),
+            expected: expected.into(),
+        }
+    }
+}
+
+/// Where `text` starts in `input`, if it's a slice of it.
+fn offset_of(input: &str, text: &str) -> Option<usize> {
+    let start = input.as_ptr() as usize;
+    let at = (text.as_ptr() as usize).checked_sub(start)?;
+    (at + text.len() <= input.len()).then_some(at)
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(input) = &self.input {
+            write!(f, "{}:", input)?;
+        }
+        write!(
+            f,
+            "{}:{}: expected {}, ",
+            self.line, self.column, self.expected
+        )?;
+        if self.text.is_empty() {
+            write!(f, "found nothing")
+        } else {
+            write!(f, "found {:?}", self.text)
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parse `text`, a slice of `input`, as a number.
+pub fn number<T: FromStr>(input: &str, text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::at(input, text, "a number"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "seeds: 79 14\n\nsoil map:\n50 98 x2\n";
+
+    #[test]
+    fn points_at_the_offending_text() {
+        let text = &INPUT[INPUT.find("x2").unwrap()..][..2];
+        let error = number::<usize>(INPUT, text).unwrap_err();
+
+        assert_eq!((error.line, error.column), (4, 7));
+        assert_eq!(
+            error.in_input("sample").to_string(),
+            r#"sample:4:7: expected a number, found "x2""#
+        );
+    }
+
+    #[test]
+    fn points_past_missing_text() {
+        let line = INPUT.lines().nth(3).unwrap();
+        let error = ParseError::after(INPUT, line, "a range length");
+        assert_eq!((error.line, error.column), (4, 9));
+
+        let error = ParseError::end(INPUT, "a location map");
+        assert_eq!((error.line, error.column), (5, 1));
+        assert_eq!(
+            error.to_string(),
+            "5:1: expected a location map, found nothing"
+        );
+    }
+}
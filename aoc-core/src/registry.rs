@@ -0,0 +1,54 @@
+use crate::runner::Solution;
+use eyre::{eyre, Result};
+
+/// A day's solution, as registered with [`register_day!`](crate::register_day).
+pub struct Registration {
+    pub year: u16,
+    pub day: u8,
+    pub solution: fn() -> Box<dyn Solution>,
+}
+
+inventory::collect!(Registration);
+
+/// Make a day's solution discoverable by the runner.
+///
+/// ```ignore
+/// register_day!(2021, 1, Day1 {});
+/// ```
+#[macro_export]
+macro_rules! register_day {
+    ($year:expr, $day:expr, $solution:expr) => {
+        $crate::inventory::submit! {
+            $crate::registry::Registration {
+                year: $year,
+                day: $day,
+                solution: || ::std::boxed::Box::new($solution),
+            }
+        }
+    };
+}
+
+/// Every registered day, ordered by year then day.
+pub fn all() -> Vec<&'static Registration> {
+    let mut days: Vec<_> = inventory::iter::<Registration>.into_iter().collect();
+    days.sort_by_key(|r| (r.year, r.day));
+    days
+}
+
+/// The registered days of one year, in order.
+pub fn days(year: u16) -> Vec<&'static Registration> {
+    all().into_iter().filter(|r| r.year == year).collect()
+}
+
+pub fn find(year: u16, day: u8) -> Result<&'static Registration> {
+    let days = days(year);
+    days.iter().find(|r| r.day == day).copied().ok_or_else(|| {
+        let available: Vec<_> = days.iter().map(|r| r.day.to_string()).collect();
+        eyre!(
+            "no solution registered for {} day {}, available days: {}",
+            year,
+            day,
+            available.join(", ")
+        )
+    })
+}
@@ -0,0 +1,466 @@
+use crate::answer::Answer;
+use crate::answers::Answers;
+use crate::day::{Day, Part};
+use crate::input::{Input, InputProvider};
+use crate::parse::ParseError;
+use crate::registry::Registration;
+use eyre::{eyre, Report, Result, WrapErr};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use std::fmt;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+/// An answer along with how long it took to produce.
+#[derive(Debug, Clone)]
+pub struct Solved {
+    pub answer: Answer,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+/// Object-safe view of a [`Day`], so days with different input types can be
+/// stored and run side by side.
+pub trait Solution {
+    fn samples(&self, part: Part) -> &'static [u8];
+    /// Parse `input` and solve `part` of the puzzle with it.
+    fn solve(&self, part: Part, input: &Input) -> Result<Solved>;
+    /// See [`Day::configure`].
+    fn configure(&mut self, key: &str, value: &str) -> Result<()>;
+}
+
+impl<D: Day> Solution for D {
+    fn samples(&self, part: Part) -> &'static [u8] {
+        Day::samples(self, part)
+    }
+
+    fn solve(&self, part: Part, input: &Input) -> Result<Solved> {
+        let start = Instant::now();
+        let parsed = self
+            .parse(&input.text)
+            .map_err(|e| name_input(e, input))
+            .wrap_err("parsing input")?;
+        let parse = start.elapsed();
+
+        let start = Instant::now();
+        let answer = match part {
+            Part::One => self.part_1(&parsed),
+            Part::Two => self.part_2(&parsed),
+        }
+        .map_err(|e| name_input(e, input))?;
+        Ok(Solved {
+            answer,
+            parse,
+            solve: start.elapsed(),
+        })
+    }
+
+    fn configure(&mut self, key: &str, value: &str) -> Result<()> {
+        Day::configure(self, key, value)
+    }
+}
+
+/// Name `input` in `e` if it's a [`ParseError`], which can't know it.
+fn name_input(e: Report, input: &Input) -> Report {
+    match e.downcast::<ParseError>() {
+        Ok(e) => e.in_input(&input.name).into(),
+        Err(e) => e,
+    }
+}
+
+/// Run both parts of a day against the inputs `provider` selects for them,
+/// printing each answer as it's found.
+pub fn run(provider: &InputProvider, solution: &dyn Solution) -> Result<()> {
+    println!("Day {}", provider.day());
+    for part in Part::ALL {
+        for input in provider.inputs(part, solution.samples(part))? {
+            let solved = solution
+                .solve(part, &input)
+                .wrap_err_with(|| format!("solving {} with {}", part, input.name))?;
+            println!(
+                "{}, {}: {} (parse {:?}, solve {:?})",
+                part, input.name, solved.answer, solved.parse, solved.solve
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Why a part didn't produce an answer.
+#[derive(Debug)]
+pub enum Failure {
+    Error(eyre::Report),
+    Panic(String),
+}
+
+impl Failure {
+    /// A one word summary for tables.
+    pub fn status(&self) -> &'static str {
+        match self {
+            Failure::Error(_) => "error",
+            Failure::Panic(_) => "panic",
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Failure::Error(report) => write!(f, "{:#}", report),
+            Failure::Panic(message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+/// Solve `part` like [`Solution::solve`], but catch a panic and report it
+/// as a [`Failure`] instead of unwinding.
+pub fn solve_caught(solution: &dyn Solution, part: Part, input: &Input) -> Result<Solved, Failure> {
+    match catch_unwind(AssertUnwindSafe(|| solution.solve(part, input))) {
+        Ok(result) => result.map_err(Failure::Error),
+        Err(payload) => Err(Failure::Panic(
+            payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "unknown panic".to_string()),
+        )),
+    }
+}
+
+/// How one part went against one input.
+#[derive(Debug)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    /// The input's name, or "-" if the inputs couldn't be loaded.
+    pub input: String,
+    pub outcome: Result<Solved, Failure>,
+    /// Wall time for the part, parsing included.
+    pub wall: Duration,
+}
+
+/// Records serialize flat, with either an answer or an error:
+///
+/// ```json
+/// {"year":2021,"day":1,"part":1,"input":"sample","answer":"7","duration_ns":2170,"error":null}
+/// ```
+impl Serialize for Record {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        let mut record = s.serialize_struct("Record", 7)?;
+        record.serialize_field("year", &self.year)?;
+        record.serialize_field("day", &self.day)?;
+        record.serialize_field("part", &self.part.number())?;
+        record.serialize_field("input", &self.input)?;
+        record.serialize_field("answer", &self.outcome.as_ref().ok().map(|s| &s.answer))?;
+        record.serialize_field("duration_ns", &(self.wall.as_nanos() as u64))?;
+        record.serialize_field("error", &self.outcome.as_ref().err().map(|f| f.to_string()))?;
+        record.end()
+    }
+}
+
+/// Run every part of each of `days` against the inputs selected for it,
+/// carrying on past errors and panics so one bad part doesn't hide the
+/// rest.
+pub fn run_all<'a>(
+    days: &[&Registration],
+    provider: impl Fn(u8) -> InputProvider<'a>,
+) -> Vec<Record> {
+    let mut records = vec![];
+    for registration in days {
+        let solution = (registration.solution)();
+        let provider = provider(registration.day);
+        for part in Part::ALL {
+            let record = |input: &str, outcome, wall| Record {
+                year: registration.year,
+                day: registration.day,
+                part,
+                input: input.to_string(),
+                outcome,
+                wall,
+            };
+            match provider.inputs(part, solution.samples(part)) {
+                Ok(inputs) => {
+                    for input in inputs {
+                        let start = Instant::now();
+                        let outcome = solve_caught(solution.as_ref(), part, &input);
+                        records.push(record(&input.name, outcome, start.elapsed()));
+                    }
+                }
+                Err(e) => records.push(record("-", Err(Failure::Error(e)), Duration::ZERO)),
+            }
+        }
+    }
+    records
+}
+
+/// Print `records` as a table, followed by the details of any failures.
+pub fn print_table(records: &[Record]) {
+    print!("{}", table(records));
+}
+
+fn table(records: &[Record]) -> String {
+    let rows = records
+        .iter()
+        .map(|r| {
+            let (answer, status) = match &r.outcome {
+                Ok(solved) => (solved.answer.to_string(), "ok"),
+                Err(failure) => ("-".to_string(), failure.status()),
+            };
+            let mut row = key_cells(r);
+            row.extend([answer, status.to_string(), format!("{:.2?}", r.wall)]);
+            row
+        })
+        .collect();
+    let mut out = render_rows(
+        &["year", "day", "part", "input", "answer", "status", "time"],
+        rows,
+    );
+    out.push_str(&render_failures(records));
+    out
+}
+
+/// An error counting the runs in `records` that failed, if any did, so
+/// the process exits with a failure status.
+pub fn check_succeeded(records: &[Record]) -> Result<()> {
+    let failed = records.iter().filter(|r| r.outcome.is_err()).count();
+    match failed {
+        0 => Ok(()),
+        _ => Err(eyre!("{} of {} runs failed", failed, records.len())),
+    }
+}
+
+/// How a record compares with its recorded answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    Mismatch,
+    /// Nothing has been recorded for this part and input yet.
+    Unrecorded,
+    Failed,
+}
+
+impl Verdict {
+    pub fn of(record: &Record, answers: &Answers) -> Self {
+        let expected = answers.get(record.day, record.part, &record.input);
+        match (&record.outcome, expected) {
+            (Err(_), _) => Verdict::Failed,
+            (Ok(_), None) => Verdict::Unrecorded,
+            (Ok(solved), Some(expected)) if solved.answer.to_string() == expected => Verdict::Match,
+            (Ok(_), Some(_)) => Verdict::Mismatch,
+        }
+    }
+}
+
+/// Print `records` as a table alongside the answers recorded for them,
+/// followed by the details of any failures.
+pub fn print_verified(records: &[Record], answers: &Answers) {
+    let rows = records
+        .iter()
+        .map(|r| {
+            let answer = match &r.outcome {
+                Ok(solved) => solved.answer.to_string(),
+                Err(_) => "-".to_string(),
+            };
+            let status = match (Verdict::of(r, answers), &r.outcome) {
+                (Verdict::Match, _) => "ok",
+                (Verdict::Mismatch, _) => "wrong",
+                (Verdict::Unrecorded, _) => "new",
+                (Verdict::Failed, Err(failure)) => failure.status(),
+                (Verdict::Failed, Ok(_)) => unreachable!(),
+            };
+            let expected = answers.get(r.day, r.part, &r.input).unwrap_or("-");
+            let mut row = key_cells(r);
+            row.extend([
+                answer,
+                expected.to_string(),
+                status.to_string(),
+                format!("{:.2?}", r.wall),
+            ]);
+            row
+        })
+        .collect();
+    print!(
+        "{}",
+        render_rows(
+            &["year", "day", "part", "input", "answer", "expected", "status", "time"],
+            rows,
+        )
+    );
+    print!("{}", render_failures(records));
+}
+
+/// The cells identifying which run a record is for.
+fn key_cells(r: &Record) -> Vec<String> {
+    vec![
+        r.year.to_string(),
+        r.day.to_string(),
+        r.part.to_string(),
+        r.input.clone(),
+    ]
+}
+
+/// `rows` under `header`, padding each column to its widest cell.
+fn render_rows(header: &[&str], rows: Vec<Vec<String>>) -> String {
+    let header: Vec<String> = header.iter().map(|h| h.to_string()).collect();
+    let mut out = String::new();
+    let mut widths = vec![0; header.len()];
+    for row in std::iter::once(&header).chain(&rows) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+    for row in std::iter::once(&header).chain(&rows) {
+        let cells: Vec<_> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:width$}", cell, width = width))
+            .collect();
+        out.push_str(cells.join("  ").trim_end());
+        out.push('\n');
+    }
+    out
+}
+
+fn render_failures(records: &[Record]) -> String {
+    let mut out = String::new();
+    for r in records {
+        if let Err(failure) = &r.outcome {
+            out.push_str(&format!(
+                "\n{} day {} {}, {}: {}\n",
+                r.year, r.day, r.part, r.input, failure
+            ));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::data::DataDirs;
+    use crate::input::{Layout, Selection, Source};
+    use crate::stand_in::TempDir;
+    use eyre::eyre;
+
+    const LAYOUT: Layout = Layout {
+        year: 2015,
+        dir: "/nonexistent",
+        input: |day| format!("day{}", day),
+        sample: |day, n| format!("day{}-sample{}", day, n),
+        source: Source {
+            dir: "/nonexistent",
+            module: |day| format!("day{}.rs", day),
+            declarations: "main.rs",
+        },
+    };
+
+    /// Answers with its input's length, unless the input says to fail.
+    struct Stub;
+
+    impl Day for Stub {
+        type Input<'a> = &'a str;
+
+        fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+            Ok(input.trim())
+        }
+
+        fn part_1(&self, input: &Self::Input<'_>) -> Result<Answer> {
+            match *input {
+                "panic" => panic!("stub gave up"),
+                "error" => Err(eyre!("stub failed")),
+                _ => Ok(input.len().into()),
+            }
+        }
+
+        fn part_2(&self, input: &Self::Input<'_>) -> Result<Answer> {
+            Ok(input.to_uppercase().into())
+        }
+    }
+
+    /// Run days 1 to 3 of the stub, whose inputs say to answer, panic and
+    /// fail in turn, from inputs in a data directory named for `test`.
+    fn run_stub(test: &str) -> Vec<Record> {
+        let root = TempDir::new(&format!("runner-{}", test));
+        std::fs::create_dir_all(&*root).unwrap();
+        for (day, text) in [(1, "abc"), (2, "panic"), (3, "error")] {
+            std::fs::write(root.join(format!("day{}", day)), text).unwrap();
+        }
+        let registrations: Vec<_> = (1..=3)
+            .map(|day| Registration {
+                year: 2015,
+                day,
+                solution: || Box::new(Stub),
+            })
+            .collect();
+        let days: Vec<_> = registrations.iter().collect();
+        let dirs = DataDirs::resolve(None, &Config::default(), &LAYOUT);
+        run_all(&days, |day| {
+            let path = root.join(format!("day{}", day));
+            InputProvider::new(&LAYOUT, dirs.clone(), day, Selection::File(path))
+        })
+    }
+
+    #[test]
+    fn catches_panics_and_errors() {
+        let records = run_stub("caught");
+        let outcomes: Vec<_> = records
+            .iter()
+            .map(|r| match &r.outcome {
+                Ok(solved) => solved.answer.to_string(),
+                Err(failure) => failure.to_string(),
+            })
+            .collect();
+        assert_eq!(
+            outcomes,
+            [
+                "3",
+                "ABC",
+                "panicked: stub gave up",
+                "PANIC",
+                "stub failed",
+                "ERROR",
+            ]
+        );
+        let statuses: Vec<_> = records
+            .iter()
+            .filter_map(|r| r.outcome.as_ref().err().map(Failure::status))
+            .collect();
+        assert_eq!(statuses, ["panic", "error"]);
+
+        let error = check_succeeded(&records).unwrap_err();
+        assert_eq!(error.to_string(), "2 of 6 runs failed");
+        assert!(check_succeeded(&records[..2]).is_ok());
+    }
+
+    #[test]
+    fn prints_a_table_and_json() {
+        let mut records = run_stub("printed");
+        for r in &mut records {
+            r.input = "input".to_string();
+            r.wall = Duration::from_millis(5);
+        }
+        let table = table(&records[..3]);
+        assert_eq!(
+            table,
+            "year  day  part    input  answer  status  time\n\
+             2015  1    part 1  input  3       ok      5.00ms\n\
+             2015  1    part 2  input  ABC     ok      5.00ms\n\
+             2015  2    part 1  input  -       panic   5.00ms\n\
+             \n\
+             2015 day 2 part 1, input: panicked: stub gave up\n"
+        );
+
+        let json: Vec<_> = [&records[0], &records[4]]
+            .iter()
+            .map(|r| serde_json::to_string(r).unwrap())
+            .collect();
+        assert_eq!(
+            json,
+            [
+                r#"{"year":2015,"day":1,"part":1,"input":"input","answer":"3","duration_ns":5000000,"error":null}"#,
+                r#"{"year":2015,"day":3,"part":1,"input":"input","answer":null,"duration_ns":5000000,"error":"stub failed"}"#,
+            ]
+        );
+    }
+}
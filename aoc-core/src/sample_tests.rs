@@ -0,0 +1,101 @@
+//! Tests for every sample that has an expected-answer sidecar, generated
+//! by each year's build script:
+//!
+//! ```ignore
+//! // build.rs
+//! #[path = "src/layout.rs"]
+//! mod layout;
+//!
+//! fn main() {
+//!     aoc_core::sample_tests::generate(&layout::LAYOUT).unwrap();
+//! }
+//! ```
+//!
+//! and pulled into the crate with
+//!
+//! ```ignore
+//! #[cfg(test)]
+//! mod sample_tests {
+//!     include!(concat!(env!("OUT_DIR"), "/sample_tests.rs"));
+//! }
+//! ```
+
+use crate::answers::Expected;
+use crate::day::Part;
+use crate::input::{Input, Layout};
+use crate::registry;
+use eyre::{eyre, Result, WrapErr};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+/// Samples are looked for on every day, up to this many per day.
+const MAX_SAMPLES: u8 = 9;
+
+/// Write `$OUT_DIR/sample_tests.rs` with a `#[test]` for each part of each
+/// sample that has an expected answer, and ask cargo to regenerate it when
+/// the samples change. Meant to be called from a build script.
+pub fn generate(layout: &Layout) -> Result<()> {
+    let out_dir = std::env::var_os("OUT_DIR").ok_or_else(|| eyre!("OUT_DIR isn't set"))?;
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed=src/layout.rs");
+    println!("cargo:rerun-if-changed={}", layout.dir);
+
+    let out = PathBuf::from(out_dir).join("sample_tests.rs");
+    std::fs::write(&out, tests(layout)?).wrap_err_with(|| format!("writing {}", out.display()))
+}
+
+/// The source of the generated tests.
+fn tests(layout: &Layout) -> Result<String> {
+    let mut out = String::from("// Generated from the samples' .expected sidecars.\n");
+    for day in 1..=25 {
+        for n in 1..=MAX_SAMPLES {
+            let sample = Path::new(layout.dir).join((layout.sample)(day, n));
+            let sidecar = Expected::path(&sample);
+            if !sidecar.is_file() {
+                continue;
+            }
+            let expected = Expected::load(&sidecar)?;
+            for part in Part::ALL {
+                if let Some(answer) = expected.get(part) {
+                    write!(
+                        out,
+                        "
+#[test]
+fn day{day}_part{part}_sample{n}() {{
+    aoc_core::sample_tests::check(
+        {year},
+        {day},
+        aoc_core::Part::{variant:?},
+        include_str!({sample:?}),
+        {answer:?},
+    );
+}}
+",
+                        year = layout.year,
+                        day = day,
+                        part = part.number(),
+                        variant = part,
+                        n = n,
+                        sample = sample.display().to_string(),
+                        answer = answer,
+                    )?;
+                }
+            }
+        }
+    }
+    Ok(out)
+}
+
+/// Solve `part` of the registered `day` with `sample` and check it gives
+/// `expected`. Called by the generated tests.
+pub fn check(year: u16, day: u8, part: Part, sample: &str, expected: &str) {
+    let registration = registry::find(year, day).unwrap_or_else(|e| panic!("{}", e));
+    let sample = Input {
+        name: "sample".to_string(),
+        text: sample.to_string(),
+    };
+    let solved = (registration.solution)()
+        .solve(part, &sample)
+        .unwrap_or_else(|e| panic!("{} day {} {}: {:?}", year, day, part, e));
+    assert_eq!(solved.answer.to_string(), expected);
+}
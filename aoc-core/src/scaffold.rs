@@ -0,0 +1,228 @@
+use crate::input::Layout;
+use crate::registry;
+use eyre::{eyre, Result, WrapErr};
+use std::fs::{create_dir_all, read_to_string, write};
+use std::path::{Component, Path, PathBuf};
+
+/// What `new` did for a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Created {
+    File(PathBuf),
+    /// A `mod` declaration was added to this file.
+    Declaration(PathBuf),
+    /// The file was already there and was left alone.
+    Kept(PathBuf),
+}
+
+/// Start a new day for `layout`'s year: a module implementing [`Day`] with
+/// a test skeleton, its registration and `mod` declaration, and empty
+/// placeholder input and sample files in the package's data directory.
+/// Refuses to touch anything if the day is already registered, or its
+/// module already exists or is declared; input files that already exist
+/// are kept.
+///
+/// [`Day`]: crate::Day
+pub fn new_day(layout: &Layout, day: u8) -> Result<Vec<Created>> {
+    if registry::find(layout.year, day).is_ok() {
+        return Err(eyre!("{} day {} is already registered", layout.year, day));
+    }
+    let src = Path::new(layout.source.dir);
+    let data_dir = Path::new(layout.dir);
+    let module_path = src.join((layout.source.module)(day));
+    let declarations_path = src.join(layout.source.declarations);
+    let module = module_name(day);
+
+    if module_path.exists() {
+        return Err(eyre!(
+            "{} day {} already exists at {}",
+            layout.year,
+            day,
+            module_path.display()
+        ));
+    }
+    let declarations = read_to_string(&declarations_path)
+        .wrap_err_with(|| format!("reading {}", declarations_path.display()))?;
+    let declarations = declare(&declarations, &module).ok_or_else(|| {
+        eyre!(
+            "{} already declares mod {}",
+            declarations_path.display(),
+            module
+        )
+    })?;
+
+    let input_path = data_dir.join((layout.input)(day));
+    let sample_path = data_dir.join((layout.sample)(day, 1));
+    let sample_include = relative(module_path.parent().unwrap_or(src), &sample_path);
+
+    let mut created = vec![];
+    for path in [&input_path, &sample_path] {
+        if path.exists() {
+            created.push(Created::Kept(path.clone()));
+        } else {
+            create_file(path, "")?;
+            created.push(Created::File(path.clone()));
+        }
+    }
+    create_file(
+        &module_path,
+        &template(layout.year, day, &sample_include.display().to_string()),
+    )?;
+    created.push(Created::File(module_path));
+    write(&declarations_path, declarations)
+        .wrap_err_with(|| format!("writing {}", declarations_path.display()))?;
+    created.push(Created::Declaration(declarations_path));
+    Ok(created)
+}
+
+fn module_name(day: u8) -> String {
+    format!("day{}", day)
+}
+
+fn create_file(path: &Path, contents: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent).wrap_err_with(|| format!("creating {}", parent.display()))?;
+    }
+    write(path, contents).wrap_err_with(|| format!("writing {}", path.display()))
+}
+
+/// Add `mod module;` to `source` among its other `mod` declarations, in
+/// the order rustfmt keeps them and with the same visibility. `None` if
+/// it's already declared.
+fn declare(source: &str, module: &str) -> Option<String> {
+    let mut lines: Vec<&str> = source.lines().collect();
+    let mods: Vec<(usize, &str, &str)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let (vis, rest) = match line.strip_prefix("pub ") {
+                Some(rest) => ("pub ", rest),
+                None => ("", *line),
+            };
+            let name = rest.strip_prefix("mod ")?.strip_suffix(';')?;
+            Some((i, vis, name))
+        })
+        .collect();
+    if mods.iter().any(|(_, _, name)| *name == module) {
+        return None;
+    }
+
+    let vis = mods.first().map_or("", |(_, vis, _)| *vis);
+    let declaration = format!("{}mod {};", vis, module);
+    let at = mods
+        .iter()
+        .find(|(_, _, name)| *name > module)
+        .or(mods.last())
+        .map_or(0, |&(i, _, name)| if name > module { i } else { i + 1 });
+    lines.insert(at, &declaration);
+    let mut out = lines.join("\n");
+    out.push('\n');
+    Some(out)
+}
+
+/// `to` relative to the directory `from`, both absolute.
+fn relative(from: &Path, to: &Path) -> PathBuf {
+    let from: Vec<Component> = from.components().collect();
+    let to: Vec<Component> = to.components().collect();
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    let mut path = PathBuf::new();
+    for _ in common..from.len() {
+        path.push("..");
+    }
+    for component in &to[common..] {
+        path.push(component);
+    }
+    path
+}
+
+fn template(year: u16, day: u8, sample: &str) -> String {
+    TEMPLATE
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &day.to_string())
+        .replace("{sample}", sample)
+}
+
+const TEMPLATE: &str = r#"use aoc_core::{register_day, Answer, Day};
+use eyre::{eyre, Result};
+
+pub struct Day{day} {}
+
+register_day!({year}, {day}, Day{day} {});
+
+impl Day for Day{day} {
+    type Input<'a> = Vec<&'a str>;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part_1(&self, _input: &Self::Input<'_>) -> Result<Answer> {
+        Err(eyre!("part 1 isn't solved yet"))
+    }
+
+    fn part_2(&self, _input: &Self::Input<'_>) -> Result<Answer> {
+        Err(eyre!("part 2 isn't solved yet"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = include_str!("{sample}");
+
+    #[test]
+    #[ignore = "not solved yet"]
+    fn part_1_sample() {
+        let input = Day{day} {}.parse(SAMPLE).unwrap();
+        assert_eq!(Day{day} {}.part_1(&input).unwrap(), Answer::from(0));
+    }
+
+    #[test]
+    #[ignore = "not solved yet"]
+    fn part_2_sample() {
+        let input = Day{day} {}.parse(SAMPLE).unwrap();
+        assert_eq!(Day{day} {}.part_2(&input).unwrap(), Answer::from(0));
+    }
+}
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn declares_modules_in_rustfmt_order() {
+        let main = "mod day1;\nmod day10;\nmod day2;\n\nuse aoc_core::Layout;\n";
+        assert_eq!(
+            declare(main, "day11").unwrap(),
+            "mod day1;\nmod day10;\nmod day11;\nmod day2;\n\nuse aoc_core::Layout;\n"
+        );
+        assert_eq!(
+            declare(main, "day3").unwrap(),
+            "mod day1;\nmod day10;\nmod day2;\nmod day3;\n\nuse aoc_core::Layout;\n"
+        );
+        assert_eq!(
+            declare("use aoc_core::Layout;\n\npub mod day1;\n", "day0").unwrap(),
+            "use aoc_core::Layout;\n\npub mod day0;\npub mod day1;\n"
+        );
+        assert_eq!(declare(main, "day10"), None);
+    }
+
+    #[test]
+    fn finds_relative_paths() {
+        assert_eq!(
+            relative(
+                Path::new("/aoc/2021/src/days"),
+                Path::new("/aoc/2021/src/data/day13-sample")
+            ),
+            Path::new("../data/day13-sample")
+        );
+        assert_eq!(
+            relative(
+                Path::new("/aoc/2020/src/day25"),
+                Path::new("/aoc/2020/src/day25/input-sample.txt")
+            ),
+            Path::new("input-sample.txt")
+        );
+    }
+}
@@ -0,0 +1,444 @@
+use crate::answer::Answer;
+use crate::answers::PartTables;
+use crate::data::DataDirs;
+use crate::day::Part;
+use crate::fetch::Client;
+use eyre::{eyre, Result, WrapErr};
+use std::fmt;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// What the puzzle server made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// The answer wasn't looked at because the last one was too recent.
+    Wait(Duration),
+    /// The part has already been solved.
+    AlreadySolved,
+    /// A response we don't recognise, as plain text.
+    Unrecognised(String),
+}
+
+impl Outcome {
+    /// Read the outcome out of the server's response page.
+    pub fn parse(html: &str) -> Self {
+        let text = article_text(html);
+        let lower = text.to_lowercase();
+        if lower.contains("that's the right answer") {
+            Outcome::Correct
+        } else if lower.contains("that's not the right answer") {
+            if lower.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if lower.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Incorrect
+            }
+        } else if lower.contains("you gave an answer too recently") {
+            let wait = lower
+                .rsplit_once("you have ")
+                .and_then(|(_, rest)| rest.split_once(" left to wait"))
+                .and_then(|(wait, _)| parse_wait(wait));
+            Outcome::Wait(wait.unwrap_or(Duration::from_secs(60)))
+        } else if lower.contains("did you already complete it") {
+            Outcome::AlreadySolved
+        } else {
+            Outcome::Unrecognised(text)
+        }
+    }
+
+    /// What gets written to the guesses file, for outcomes that say
+    /// something about the answer.
+    fn label(&self) -> Option<&'static str> {
+        match self {
+            Outcome::Correct => Some("correct"),
+            Outcome::Incorrect => Some("incorrect"),
+            Outcome::TooHigh => Some("too high"),
+            Outcome::TooLow => Some("too low"),
+            Outcome::Wait(_) | Outcome::AlreadySolved | Outcome::Unrecognised(_) => None,
+        }
+    }
+
+    /// How long the server won't look at another answer for after this
+    /// one. A wrong answer costs at least a minute; if the server wants
+    /// longer, the next submission comes back as a [`Outcome::Wait`].
+    fn cooldown(&self) -> Option<Duration> {
+        match self {
+            Outcome::Wait(wait) => Some(*wait),
+            Outcome::Incorrect | Outcome::TooHigh | Outcome::TooLow => {
+                Some(Duration::from_secs(60))
+            }
+            Outcome::Correct | Outcome::AlreadySolved | Outcome::Unrecognised(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Outcome::Wait(wait) => write!(f, "submitted too recently, wait {}s", wait.as_secs()),
+            Outcome::AlreadySolved => write!(f, "already solved"),
+            Outcome::Unrecognised(text) => write!(f, "unrecognised response: {}", text),
+            _ => write!(f, "{}", self.label().unwrap()),
+        }
+    }
+}
+
+/// The text of the page's `<article>`, where the server puts its verdict,
+/// with tags stripped and whitespace collapsed.
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map(|(_, rest)| rest.split_once("</article>").map_or(rest, |(a, _)| a))
+        .unwrap_or(html);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for ch in article.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parse a wait like "1m 5s" or "34s".
+fn parse_wait(wait: &str) -> Option<Duration> {
+    let mut secs = 0;
+    for token in wait.split_whitespace() {
+        let unit = match token.chars().last()? {
+            'h' => 3600,
+            'm' => 60,
+            's' => 1,
+            _ => return None,
+        };
+        let n: u64 = token[..token.len() - 1].parse().ok()?;
+        secs += n * unit;
+    }
+    Some(Duration::from_secs(secs))
+}
+
+impl Client {
+    /// Post `answer` for `part` of `day` of `year`.
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &Answer) -> Result<Outcome> {
+        let path = format!("/{}/day/{}/answer", year, day);
+        let response = self
+            .request("POST", &path)?
+            .send_form(&[
+                ("level", &part.number().to_string()),
+                ("answer", &answer.to_string()),
+            ])
+            .map_err(|e| eyre!(e))
+            .wrap_err_with(|| format!("posting to {}", path))?;
+        let html = response
+            .into_string()
+            .wrap_err_with(|| format!("reading response from {}", path))?;
+        Ok(Outcome::parse(&html))
+    }
+}
+
+/// Every answer submitted for a year and what the server said about it,
+/// kept next to the inputs. On disk it's a table per day and part, keyed
+/// by answer:
+///
+/// ```toml
+/// next_submission = 1700000000
+///
+/// [day1.part1]
+/// 1234 = "too low"
+/// 2345 = "correct"
+/// ```
+///
+/// `next_submission` is when, in seconds since the Unix epoch, the server
+/// will next look at an answer.
+#[derive(Debug)]
+pub struct Guesses(PartTables);
+
+impl Guesses {
+    pub const FILE_NAME: &'static str = "guesses.toml";
+    const NEXT_SUBMISSION: &'static str = "next_submission";
+
+    pub fn load(dirs: &DataDirs) -> Result<Self> {
+        PartTables::load(dirs, Self::FILE_NAME).map(Self)
+    }
+
+    /// Where the guesses were loaded from, and will be saved to.
+    pub fn path(&self) -> &Path {
+        self.0.path()
+    }
+
+    /// Why `answer` shouldn't be submitted for `part` of `day`, if it's
+    /// already been tried, the part has been solved, or an earlier too high
+    /// or too low guess rules it out.
+    pub fn refusal(&self, day: u8, part: Part, answer: &Answer) -> Option<String> {
+        let answer = answer.to_string();
+        let number: Option<i128> = answer.parse().ok();
+        for (guess, outcome) in self.0.table(day, part) {
+            if guess == answer {
+                return Some(format!("{} was already submitted: {}", answer, outcome));
+            }
+            if outcome == "correct" {
+                return Some(format!("already solved with {}", guess));
+            }
+            let bound: Option<i128> = guess.parse().ok();
+            match (number, bound, outcome) {
+                (Some(n), Some(b), "too high") if n >= b => {
+                    return Some(format!("{} was too high, so {} is too", guess, answer))
+                }
+                (Some(n), Some(b), "too low") if n <= b => {
+                    return Some(format!("{} was too low, so {} is too", guess, answer))
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// How much longer the server won't look at answers for, as of `now`.
+    pub fn wait(&self, now: SystemTime) -> Option<Duration> {
+        let next = UNIX_EPOCH + Duration::from_secs(self.0.number(Self::NEXT_SUBMISSION)?);
+        next.duration_since(now).ok().filter(|wait| !wait.is_zero())
+    }
+
+    /// Remember what the server said about `answer`, if it said anything
+    /// about the answer itself, and how long it'll be until it looks at
+    /// another one.
+    pub fn record(&mut self, day: u8, part: Part, answer: &Answer, outcome: &Outcome) {
+        if let Some(label) = outcome.label() {
+            self.0
+                .set(day, part, &answer.to_string(), label.to_string());
+        }
+        if let Some(cooldown) = outcome.cooldown() {
+            let next = (SystemTime::now() + cooldown)
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default();
+            // round up so the wait is never cut short
+            let secs = next.as_secs() + u64::from(next.subsec_nanos() > 0);
+            self.0.set_number(Self::NEXT_SUBMISSION, secs);
+        }
+    }
+
+    pub fn save(&self) -> Result<()> {
+        self.0.save()
+    }
+}
+
+/// Submit `answer` unless `guesses` rule it out, and remember the outcome.
+pub fn submit(
+    client: &Client,
+    guesses: &mut Guesses,
+    (year, day): (u16, u8),
+    part: Part,
+    answer: &Answer,
+) -> Result<Outcome> {
+    if let Some(refusal) = guesses.refusal(day, part, answer) {
+        return Err(eyre!(
+            "not submitting {} day {} {}: {}",
+            year,
+            day,
+            part,
+            refusal
+        ));
+    }
+    if let Some(wait) = guesses.wait(SystemTime::now()) {
+        return Err(eyre!(
+            "not submitting {} day {} {}: the server won't look at answers for another {}s",
+            year,
+            day,
+            part,
+            wait.as_secs() + 1
+        ));
+    }
+    let outcome = client.submit(year, day, part, answer)?;
+    guesses.record(day, part, answer, &outcome);
+    guesses.save()?;
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::input::{Layout, Source};
+    use crate::stand_in::{serve, TempDir};
+
+    const LAYOUT: Layout = Layout {
+        year: 2021,
+        dir: "/nonexistent",
+        input: |day| format!("day{}/input.txt", day),
+        sample: |day, _| format!("day{}/sample.txt", day),
+        source: Source {
+            dir: "/nonexistent",
+            module: |day| format!("day{}.rs", day),
+            declarations: "main.rs",
+        },
+    };
+
+    fn page(verdict: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            verdict
+        )
+    }
+
+    /// Guesses kept in a fresh data directory root for one test.
+    fn guesses(name: &str) -> (TempDir, Guesses) {
+        let root = TempDir::new(&format!("submit-{}", name));
+        std::fs::create_dir_all(root.join("2021")).unwrap();
+        let dirs = DataDirs::resolve(Some(&root), &Config::default(), &LAYOUT);
+        (root, Guesses::load(&dirs).unwrap())
+    }
+
+    #[test]
+    fn parses_outcomes() {
+        let cases = [
+            (
+                "That's the right answer! You are <span class=\"day-success\">one gold star</span> closer.",
+                Outcome::Correct,
+            ),
+            (
+                "That's not the right answer.  If you're stuck, make sure you're using the full input data. Please wait one minute before trying again.",
+                Outcome::Incorrect,
+            ),
+            (
+                "That's not the right answer; your answer is too high.  Please wait one minute before trying again.",
+                Outcome::TooHigh,
+            ),
+            (
+                "That's not the right answer; your answer is too low.  Please wait one minute before trying again.",
+                Outcome::TooLow,
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 34s left to wait. <a href=\"/2021/day/1\">[Return to Day 1]</a>",
+                Outcome::Wait(Duration::from_secs(34)),
+            ),
+            (
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 12s left to wait.",
+                Outcome::Wait(Duration::from_secs(252)),
+            ),
+            (
+                "You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2021/day/1\">[Return to Day 1]</a>",
+                Outcome::AlreadySolved,
+            ),
+            (
+                "Something <em>new</em>.",
+                Outcome::Unrecognised("Something new.".to_string()),
+            ),
+        ];
+        for (verdict, outcome) in cases {
+            assert_eq!(Outcome::parse(&page(verdict)), outcome, "{}", verdict);
+        }
+    }
+
+    #[test]
+    fn posts_the_answer_and_records_the_outcome() {
+        let (root, mut guesses) = guesses("post");
+        let (url, server) = serve(vec![(
+            200,
+            page("That's not the right answer; your answer is too low."),
+        )]);
+        let client = Client::new(url, Some("abc123".to_string()));
+
+        let outcome = submit(&client, &mut guesses, (2021, 3), Part::Two, &1234.into()).unwrap();
+
+        assert_eq!(outcome, Outcome::TooLow);
+        let requests = server.join().unwrap();
+        assert_eq!(requests[0].line, "POST /2021/day/3/answer HTTP/1.1");
+        assert_eq!(requests[0].header("cookie"), Some("session=abc123"));
+        assert_eq!(requests[0].body, "level=2&answer=1234");
+        let saved = std::fs::read_to_string(root.join("2021").join(Guesses::FILE_NAME)).unwrap();
+        let (next, tables) = saved.split_once("\n\n").unwrap();
+        assert!(next.starts_with("next_submission = "), "{}", saved);
+        assert_eq!(tables, "[day3.part2]\n1234 = \"too low\"\n");
+    }
+
+    #[test]
+    fn refuses_known_guesses_without_asking_the_server() {
+        let (_root, mut guesses) = guesses("refuse");
+        guesses.record(5, Part::One, &100.into(), &Outcome::TooHigh);
+        guesses.record(5, Part::One, &10.into(), &Outcome::TooLow);
+        guesses.record(5, Part::Two, &"abc".into(), &Outcome::Incorrect);
+        guesses.record(6, Part::One, &42.into(), &Outcome::Correct);
+        // nothing listens here, so any request would fail.
+        let client = Client::new("http://127.0.0.1:9", Some("abc123".to_string()));
+
+        for (day, part, answer, reason) in [
+            (5, Part::One, Answer::from(100), "already submitted"),
+            (5, Part::One, Answer::from(150), "too high"),
+            (5, Part::One, Answer::from(3), "too low"),
+            (5, Part::Two, Answer::from("abc"), "already submitted"),
+            (6, Part::One, Answer::from(43), "already solved"),
+        ] {
+            let err = submit(&client, &mut guesses, (2021, day), part, &answer).unwrap_err();
+            assert!(err.to_string().contains(reason), "{}", err);
+        }
+        assert_eq!(guesses.refusal(5, Part::One, &50.into()), None);
+    }
+
+    #[test]
+    fn does_not_record_a_rate_limited_guess() {
+        let (_root, mut guesses) = guesses("wait");
+        let (url, server) = serve(vec![(
+            200,
+            page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait."),
+        )]);
+        let client = Client::new(url, Some("abc123".to_string()));
+
+        let outcome = submit(&client, &mut guesses, (2021, 1), Part::One, &7.into()).unwrap();
+
+        assert_eq!(outcome, Outcome::Wait(Duration::from_secs(65)));
+        assert_eq!(guesses.refusal(1, Part::One, &7.into()), None);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn waits_out_the_cooldown_before_submitting_again() {
+        let (root, mut guesses) = guesses("cooldown");
+        let (url, server) = serve(vec![(
+            200,
+            page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait."),
+        )]);
+        let client = Client::new(url, Some("abc123".to_string()));
+        submit(&client, &mut guesses, (2021, 1), Part::One, &7.into()).unwrap();
+        server.join().unwrap();
+
+        let now = SystemTime::now();
+        let wait = guesses.wait(now).unwrap();
+        assert!(
+            wait > Duration::from_secs(60) && wait <= Duration::from_secs(66),
+            "{:?}",
+            wait
+        );
+        assert_eq!(guesses.wait(now + Duration::from_secs(66)), None);
+
+        // the deadline survives a reload, and nothing listens here, so any
+        // request would fail.
+        let dirs = DataDirs::resolve(Some(&root), &Config::default(), &LAYOUT);
+        let mut guesses = Guesses::load(&dirs).unwrap();
+        let client = Client::new("http://127.0.0.1:9", Some("abc123".to_string()));
+        let err = submit(&client, &mut guesses, (2021, 1), Part::One, &7.into()).unwrap_err();
+        assert!(err.to_string().contains("won't look at answers"), "{}", err);
+    }
+
+    #[test]
+    fn a_wrong_answer_starts_a_cooldown() {
+        let (_root, mut guesses) = guesses("wrong");
+        let now = SystemTime::now();
+        guesses.record(2, Part::One, &5.into(), &Outcome::Correct);
+        assert_eq!(guesses.wait(now), None);
+        guesses.record(2, Part::Two, &5.into(), &Outcome::TooLow);
+        let wait = guesses.wait(now).unwrap();
+        assert!(
+            wait >= Duration::from_secs(60) && wait <= Duration::from_secs(62),
+            "{:?}",
+            wait
+        );
+    }
+}
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{OnceLock, RwLock};
+
+/// A name interned in a table shared by the whole program, so it's a small
+/// `Copy` id that compares and hashes like a number but still prints as
+/// the name.
+///
+/// Interned names live until the program exits, which suits the handful of
+/// names a puzzle input has.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Symbol(u32);
+
+#[derive(Default)]
+struct Interner {
+    names: Vec<&'static str>,
+    symbols: HashMap<&'static str, Symbol>,
+}
+
+fn interner() -> &'static RwLock<Interner> {
+    static INTERNER: OnceLock<RwLock<Interner>> = OnceLock::new();
+    INTERNER.get_or_init(Default::default)
+}
+
+impl Symbol {
+    /// The symbol for `name`, the same one every time it's asked for.
+    pub fn new(name: &str) -> Symbol {
+        if let Some(&symbol) = interner().read().unwrap().symbols.get(name) {
+            return symbol;
+        }
+        let mut interner = interner().write().unwrap();
+        // someone else may have got here between the locks
+        if let Some(&symbol) = interner.symbols.get(name) {
+            return symbol;
+        }
+        let name: &'static str = Box::leak(name.into());
+        let symbol = Symbol(interner.names.len() as u32);
+        interner.names.push(name);
+        interner.symbols.insert(name, symbol);
+        symbol
+    }
+
+    pub fn as_str(self) -> &'static str {
+        interner().read().unwrap().names[self.0 as usize]
+    }
+}
+
+impl From<&str> for Symbol {
+    fn from(name: &str) -> Self {
+        Symbol::new(name)
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl fmt::Debug for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{:?}", self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interns_names() {
+        let seed = Symbol::new("seed");
+        assert_eq!(seed, Symbol::new(&String::from("seed")));
+        assert_eq!(seed, "seed".into());
+        assert_ne!(seed, Symbol::new("soil"));
+        assert_eq!(seed.as_str(), "seed");
+        assert_eq!(Symbol::new(&seed.to_string()), seed);
+    }
+
+    #[test]
+    fn prints_as_the_name() {
+        let symbol = Symbol::new("shiny gold");
+        assert_eq!(
+            format!("{}|{:>11}", symbol, symbol),
+            "shiny gold| shiny gold"
+        );
+        assert_eq!(format!("{:?}", [symbol]), "[\"shiny gold\"]");
+    }
+}